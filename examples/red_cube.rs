@@ -1,11 +1,14 @@
 use donkey::{
-    colors::color, keys::Key, vector3, Camera3D, CameraMode, CameraProjection,
-    Window,
+    camera::{CameraController, ThirdPersonController},
+    colors::color,
+    input::InputState,
+    keys::Key,
+    vector3, Camera3D, CameraProjection, DonkeyError, Window,
 };
 
 /// basic example based on Tsoding's "Ridiculously Easy 3D in C" video:
 /// https://www.youtube.com/watch?v=K7hWqxC_7Mw
-fn main() {
+fn main() -> Result<(), DonkeyError> {
     let width = 800;
     let height = 600;
     let title = "review";
@@ -18,8 +21,9 @@ fn main() {
         90.0,
         CameraProjection::Perspective,
     );
-    let win = Window::init(width, height, title);
+    let mut win = Window::init(width, height, title)?;
     let background = color(0x181818AA);
+    let mut controller = ThirdPersonController::default();
     while !win.should_close() {
         let dt = win.get_frame_time();
         if win.is_key_down(Key::W) {
@@ -34,18 +38,19 @@ fn main() {
         if win.is_key_down(Key::A) {
             camera.position.x -= camera_speed * dt;
         }
-        win.begin_drawing();
-        win.clear_background(background);
-        win.begin_mode3d(camera);
-        win.update_camera(&mut camera, CameraMode::ThirdPerson);
-        win.draw_cube(
-            vector3!(0.0, 0.0, 0.0),
-            cube_size,
-            cube_size,
-            cube_size,
-            0xff0000ff,
-        );
-        win.end_mode3d();
-        win.end_drawing();
+        let input = InputState::capture(&win);
+        controller.update(&mut camera, &input, dt);
+        let mut canvas = win.draw();
+        canvas.clear_background(background);
+        canvas.mode3d(&camera, |c3d| {
+            c3d.draw_cube(
+                vector3!(0.0, 0.0, 0.0),
+                cube_size,
+                cube_size,
+                cube_size,
+                0xff0000ff,
+            );
+        });
     }
+    Ok(())
 }
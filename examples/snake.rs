@@ -3,14 +3,14 @@ use std::time::UNIX_EPOCH;
 use donkey::{
     colors::{color, GREEN, RED, WHITE},
     keys::Key,
-    window_ex::with_window,
+    DonkeyError, Window,
 };
 use raylib_sys::Color;
 
 const WIDTH: i32 = 800;
 const HEIGHT: i32 = 600;
 const TITLE: &str = "snake";
-const SQR_SIZE: usize = 40;
+const SQR_SIZE: i32 = 40;
 const SNAKE_COLOR: Color = GREEN;
 
 enum Direction {
@@ -21,8 +21,8 @@ enum Direction {
 }
 
 struct Snake {
-    x: usize,
-    y: usize,
+    x: i32,
+    y: i32,
     dir: Direction,
 }
 
@@ -34,26 +34,14 @@ fn rand_float() -> f32 {
     rand() as f32 / u32::MAX as f32
 }
 
-fn main() {
+fn main() -> Result<(), DonkeyError> {
     let background = color(0x181818AA);
     let mut s = Snake { x: 0, y: 0, dir: Direction::East };
     let mut apple = None;
-    // TODO builder here for window settings like fps
-    with_window(WIDTH, HEIGHT, TITLE, |win| {
-        win.set_target_fps(60);
-        win.draw(|canvas| {
+    Window::builder(WIDTH, HEIGHT, TITLE)
+        .target_fps(60)
+        .run(|win| {
             let dt = win.get_frame_time();
-            canvas.clear_background(background);
-            // tile the background like tsoding
-            for x in (0..WIDTH as usize).step_by(SQR_SIZE) {
-                for y in (0..HEIGHT as usize).step_by(SQR_SIZE) {
-                    if (x + y) / SQR_SIZE % 2 == 0 {
-                        canvas.draw_rectangle(x, y, SQR_SIZE, SQR_SIZE, RED);
-                    }
-                }
-            }
-            // draw the snake
-            canvas.draw_rectangle(s.x, s.y, SQR_SIZE, SQR_SIZE, SNAKE_COLOR);
             if win.is_key_pressed(Key::W) {
                 s.dir = Direction::North;
             }
@@ -66,37 +54,54 @@ fn main() {
             if win.is_key_pressed(Key::D) {
                 s.dir = Direction::East;
             }
-            let delta = (5.0 * dt * SQR_SIZE as f32) as usize;
+            // wrap around once the square is fully off the screen
+            let delta = (5.0 * dt * SQR_SIZE as f32) as i32;
             match s.dir {
-                Direction::North => match s.y.checked_sub(delta) {
-                    Some(v) => s.y = v,
-                    None => s.y = HEIGHT as usize,
-                },
+                Direction::North => {
+                    s.y -= delta;
+                    if s.y < -SQR_SIZE {
+                        s.y = HEIGHT;
+                    }
+                }
                 Direction::South => {
                     s.y += delta;
-                    if s.y > HEIGHT as usize {
-                        s.y = 0;
+                    if s.y > HEIGHT {
+                        s.y = -SQR_SIZE;
                     }
                 }
                 Direction::East => {
                     s.x += delta;
-                    if s.x > WIDTH as usize {
-                        s.x = 0;
+                    if s.x > WIDTH {
+                        s.x = -SQR_SIZE;
+                    }
+                }
+                Direction::West => {
+                    s.x -= delta;
+                    if s.x < -SQR_SIZE {
+                        s.x = WIDTH;
                     }
                 }
-                Direction::West => match s.x.checked_sub(delta) {
-                    Some(v) => s.x = v,
-                    None => s.x = WIDTH as usize,
-                },
             };
+
+            let canvas = win.draw();
+            canvas.clear_background(background);
+            // tile the background like tsoding
+            for x in (0..WIDTH).step_by(SQR_SIZE as usize) {
+                for y in (0..HEIGHT).step_by(SQR_SIZE as usize) {
+                    if ((x + y) / SQR_SIZE) % 2 == 0 {
+                        canvas.draw_rectangle(x, y, SQR_SIZE, SQR_SIZE, RED);
+                    }
+                }
+            }
+            // draw the snake
+            canvas.draw_rectangle(s.x, s.y, SQR_SIZE, SQR_SIZE, SNAKE_COLOR);
             // draw apples
             if let Some((x, y)) = apple {
                 canvas.draw_rectangle(x, y, SQR_SIZE, SQR_SIZE, WHITE);
             } else if rand_float() < 0.5 {
                 let x = rand() % WIDTH as u32;
                 let y = rand() % HEIGHT as u32;
-                apple = Some((x as usize, y as usize));
+                apple = Some((x as i32, y as i32));
             }
-        });
-    });
+        })
 }
@@ -26,9 +26,20 @@ fn main() {
         .blocklist_item("FP_ZERO")
         .blocklist_item("FP_SUBNORMAL")
         .blocklist_item("FP_NORMAL")
+        // printed as a 4x4 grid by matrix.rs instead
+        .no_debug("Matrix")
         .generate()
         .expect("Unable to generate bindings");
 
     let out_path = PathBuf::from(env::var("OUT_DIR").unwrap());
     bind.write_to_file(out_path.join("bindings.rs")).unwrap();
+
+    // raymath for the tests to compare against, see src/raymath_c.rs
+    #[cfg(feature = "raymath-c-tests")]
+    {
+        println!("cargo:rerun-if-changed=raymath_shim.c");
+        cc::Build::new()
+            .file("raymath_shim.c")
+            .compile("raymath_shim");
+    }
 }
@@ -0,0 +1,88 @@
+// Out-of-line copies of the inline raymath.h functions, so that the tests
+// can check the Rust ports in src/ against the C originals. Each wrapper has
+// a `c_` prefix so it cannot clash with any copy exported by libraylib
+
+#define RAYMATH_STATIC_INLINE
+#include <raymath.h>
+
+#define WRAP1(ret, name, t1) \
+    ret c_##name(t1 a) { return name(a); }
+#define WRAP2(ret, name, t1, t2) \
+    ret c_##name(t1 a, t2 b) { return name(a, b); }
+#define WRAP3(ret, name, t1, t2, t3) \
+    ret c_##name(t1 a, t2 b, t3 c) { return name(a, b, c); }
+#define WRAP4(ret, name, t1, t2, t3, t4) \
+    ret c_##name(t1 a, t2 b, t3 c, t4 d) { return name(a, b, c, d); }
+#define WRAP5(ret, name, t1, t2, t3, t4, t5) \
+    ret c_##name(t1 a, t2 b, t3 c, t4 d, t5 e) { return name(a, b, c, d, e); }
+
+WRAP2(Vector2, Vector2Add, Vector2, Vector2)
+WRAP2(Vector2, Vector2AddValue, Vector2, float)
+WRAP2(Vector2, Vector2Subtract, Vector2, Vector2)
+WRAP2(Vector2, Vector2SubtractValue, Vector2, float)
+WRAP1(float, Vector2Length, Vector2)
+WRAP1(float, Vector2LengthSqr, Vector2)
+WRAP2(float, Vector2DotProduct, Vector2, Vector2)
+WRAP2(float, Vector2CrossProduct, Vector2, Vector2)
+WRAP2(float, Vector2Distance, Vector2, Vector2)
+WRAP2(float, Vector2DistanceSqr, Vector2, Vector2)
+WRAP2(float, Vector2Angle, Vector2, Vector2)
+WRAP2(float, Vector2LineAngle, Vector2, Vector2)
+WRAP2(Vector2, Vector2Scale, Vector2, float)
+WRAP2(Vector2, Vector2Multiply, Vector2, Vector2)
+WRAP1(Vector2, Vector2Negate, Vector2)
+WRAP2(Vector2, Vector2Divide, Vector2, Vector2)
+WRAP1(Vector2, Vector2Normalize, Vector2)
+WRAP2(Vector2, Vector2Transform, Vector2, Matrix)
+WRAP3(Vector2, Vector2Lerp, Vector2, Vector2, float)
+WRAP2(Vector2, Vector2Reflect, Vector2, Vector2)
+WRAP2(Vector2, Vector2Min, Vector2, Vector2)
+WRAP2(Vector2, Vector2Max, Vector2, Vector2)
+WRAP2(Vector2, Vector2Rotate, Vector2, float)
+WRAP3(Vector2, Vector2MoveTowards, Vector2, Vector2, float)
+WRAP1(Vector2, Vector2Invert, Vector2)
+WRAP3(Vector2, Vector2Clamp, Vector2, Vector2, Vector2)
+WRAP3(Vector2, Vector2ClampValue, Vector2, float, float)
+WRAP2(int, Vector2Equals, Vector2, Vector2)
+WRAP3(Vector2, Vector2Refract, Vector2, Vector2, float)
+
+WRAP2(Vector3, Vector3Add, Vector3, Vector3)
+WRAP2(Vector3, Vector3AddValue, Vector3, float)
+WRAP2(Vector3, Vector3Subtract, Vector3, Vector3)
+WRAP2(Vector3, Vector3SubtractValue, Vector3, float)
+WRAP2(Vector3, Vector3Scale, Vector3, float)
+WRAP2(Vector3, Vector3Multiply, Vector3, Vector3)
+WRAP2(Vector3, Vector3CrossProduct, Vector3, Vector3)
+WRAP1(Vector3, Vector3Perpendicular, Vector3)
+WRAP1(float, Vector3Length, Vector3)
+WRAP1(float, Vector3LengthSqr, Vector3)
+WRAP2(float, Vector3DotProduct, Vector3, Vector3)
+WRAP2(float, Vector3Distance, Vector3, Vector3)
+WRAP2(float, Vector3DistanceSqr, Vector3, Vector3)
+WRAP2(float, Vector3Angle, Vector3, Vector3)
+WRAP1(Vector3, Vector3Negate, Vector3)
+WRAP2(Vector3, Vector3Divide, Vector3, Vector3)
+WRAP1(Vector3, Vector3Normalize, Vector3)
+WRAP2(Vector3, Vector3Project, Vector3, Vector3)
+WRAP2(Vector3, Vector3Reject, Vector3, Vector3)
+WRAP2(Vector3, Vector3Transform, Vector3, Matrix)
+WRAP2(Vector3, Vector3RotateByQuaternion, Vector3, Quaternion)
+WRAP3(Vector3, Vector3RotateByAxisAngle, Vector3, Vector3, float)
+WRAP3(Vector3, Vector3MoveTowards, Vector3, Vector3, float)
+WRAP3(Vector3, Vector3Lerp, Vector3, Vector3, float)
+WRAP5(Vector3, Vector3CubicHermite, Vector3, Vector3, Vector3, Vector3, float)
+WRAP2(Vector3, Vector3Reflect, Vector3, Vector3)
+WRAP2(Vector3, Vector3Min, Vector3, Vector3)
+WRAP2(Vector3, Vector3Max, Vector3, Vector3)
+WRAP4(Vector3, Vector3Barycenter, Vector3, Vector3, Vector3, Vector3)
+WRAP3(Vector3, Vector3Unproject, Vector3, Matrix, Matrix)
+WRAP1(Vector3, Vector3Invert, Vector3)
+WRAP3(Vector3, Vector3Clamp, Vector3, Vector3, Vector3)
+WRAP3(Vector3, Vector3ClampValue, Vector3, float, float)
+WRAP2(int, Vector3Equals, Vector3, Vector3)
+WRAP3(Vector3, Vector3Refract, Vector3, Vector3, float)
+
+void c_Vector3OrthoNormalize(Vector3 *v1, Vector3 *v2)
+{
+    Vector3OrthoNormalize(v1, v2);
+}
@@ -0,0 +1,166 @@
+use crate::{BoundingBox, Matrix, Vector3};
+
+impl BoundingBox {
+    pub fn new(min: Vector3, max: Vector3) -> Self {
+        Self { min, max }
+    }
+
+    /// the smallest box containing all of `points`, or `None` if there are
+    /// none
+    pub fn from_points(
+        points: impl IntoIterator<Item = Vector3>,
+    ) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        let mut bounds = Self::new(first, first);
+        for point in points {
+            bounds = bounds.extend(point);
+        }
+        Some(bounds)
+    }
+
+    /// the box grown to contain `point`
+    pub fn extend(&self, point: Vector3) -> Self {
+        Self::new(self.min.min(point), self.max.max(point))
+    }
+
+    /// the smallest box containing both
+    pub fn merge(&self, other: &BoundingBox) -> Self {
+        Self::new(self.min.min(other.min), self.max.max(other.max))
+    }
+
+    pub fn center(&self) -> Vector3 {
+        self.min.lerp(self.max, 0.5)
+    }
+
+    pub fn size(&self) -> Vector3 {
+        self.max - self.min
+    }
+
+    /// half of the size, from the center to the max corner
+    pub fn extents(&self) -> Vector3 {
+        self.size().scale(0.5)
+    }
+
+    /// the 8 corners, bottom face (min y) first
+    pub fn corners(&self) -> [Vector3; 8] {
+        let (a, b) = (self.min, self.max);
+        [
+            Vector3::new(a.x, a.y, a.z),
+            Vector3::new(b.x, a.y, a.z),
+            Vector3::new(b.x, a.y, b.z),
+            Vector3::new(a.x, a.y, b.z),
+            Vector3::new(a.x, b.y, a.z),
+            Vector3::new(b.x, b.y, a.z),
+            Vector3::new(b.x, b.y, b.z),
+            Vector3::new(a.x, b.y, b.z),
+        ]
+    }
+
+    /// whether `point` is inside or on the surface
+    pub fn contains(&self, point: Vector3) -> bool {
+        point.x >= self.min.x
+            && point.x <= self.max.x
+            && point.y >= self.min.y
+            && point.y <= self.max.y
+            && point.z >= self.min.z
+            && point.z <= self.max.z
+    }
+
+    /// whether the boxes overlap or touch, like `CheckCollisionBoxes`
+    pub fn intersects(&self, other: &BoundingBox) -> bool {
+        self.max.x >= other.min.x
+            && self.min.x <= other.max.x
+            && self.max.y >= other.min.y
+            && self.min.y <= other.max.y
+            && self.max.z >= other.min.z
+            && self.min.z <= other.max.z
+    }
+
+    /// whether the sphere overlaps or touches the box, like
+    /// `CheckCollisionBoxSphere`
+    pub fn intersects_sphere(&self, center: Vector3, radius: f32) -> bool {
+        let closest = center.clamp(self.min, self.max);
+        closest.distance_sqr(center) <= radius * radius
+    }
+
+    /// the axis-aligned box containing this one after `mat` moves it, which is
+    /// larger than the original when rotated
+    pub fn transform(&self, mat: Matrix) -> Self {
+        let corners = self.corners().map(|corner| corner.transform(mat));
+        Self::from_points(corners).unwrap()
+    }
+}
+
+impl PartialEq for BoundingBox {
+    fn eq(&self, other: &Self) -> bool {
+        self.min == other.min && self.max == other.max
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::FRAC_PI_4;
+
+    use super::*;
+
+    fn v(x: f32, y: f32, z: f32) -> Vector3 {
+        Vector3::new(x, y, z)
+    }
+
+    fn close(a: Vector3, b: Vector3) -> bool {
+        (a - b).length() < 1e-5
+    }
+
+    #[test]
+    fn from_points() {
+        let points = [v(1.0, -2.0, 0.0), v(-1.0, 3.0, 2.0), v(0.0, 0.0, -4.0)];
+        let bounds = BoundingBox::from_points(points).unwrap();
+        assert_eq!(
+            bounds,
+            BoundingBox::new(v(-1.0, -2.0, -4.0), v(1.0, 3.0, 2.0))
+        );
+        assert!(points.iter().all(|&p| bounds.contains(p)));
+        assert_eq!(bounds.center(), v(0.0, 0.5, -1.0));
+        assert_eq!(bounds.size(), v(2.0, 5.0, 6.0));
+        assert_eq!(bounds.extents(), v(1.0, 2.5, 3.0));
+        assert!(BoundingBox::from_points([]).is_none());
+    }
+
+    #[test]
+    fn merge_and_intersect() {
+        let a = BoundingBox::new(v(0.0, 0.0, 0.0), v(1.0, 1.0, 1.0));
+        let b = BoundingBox::new(v(2.0, 0.5, 0.5), v(3.0, 2.0, 1.0));
+        assert_eq!(
+            a.merge(&b),
+            BoundingBox::new(v(0.0, 0.0, 0.0), v(3.0, 2.0, 1.0))
+        );
+        assert!(!a.intersects(&b));
+        // touching faces count
+        let c = BoundingBox::new(v(1.0, 0.5, 0.5), v(3.0, 2.0, 1.0));
+        assert!(a.intersects(&c));
+        assert!(c.intersects(&a));
+        assert!(!a.contains(v(0.5, 0.5, 1.5)));
+    }
+
+    #[test]
+    fn sphere() {
+        let bounds = BoundingBox::new(v(0.0, 0.0, 0.0), v(1.0, 1.0, 1.0));
+        assert!(bounds.intersects_sphere(v(0.5, 0.5, 0.5), 0.1));
+        assert!(bounds.intersects_sphere(v(2.0, 0.5, 0.5), 1.0));
+        assert!(!bounds.intersects_sphere(v(2.0, 2.0, 0.5), 1.0));
+        assert!(bounds.intersects_sphere(v(1.5, 1.5, 0.5), 0.75));
+    }
+
+    #[test]
+    fn transform() {
+        let bounds = BoundingBox::new(v(-1.0, -1.0, -1.0), v(1.0, 1.0, 1.0));
+        let moved = bounds.transform(Matrix::translate(1.0, 2.0, 3.0));
+        assert_eq!(moved, BoundingBox::new(v(0.0, 1.0, 2.0), v(2.0, 3.0, 4.0)));
+
+        let rotated = bounds.transform(Matrix::rotate_y(FRAC_PI_4));
+        let r = 2.0f32.sqrt();
+        assert!(close(rotated.min, v(-r, -1.0, -r)));
+        assert!(close(rotated.max, v(r, 1.0, r)));
+    }
+}
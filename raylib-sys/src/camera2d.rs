@@ -0,0 +1,171 @@
+use crate::{Camera2D, Rectangle, Vector2};
+
+impl Camera2D {
+    /// a camera that draws `target` at the screen position `offset`
+    pub fn new(
+        offset: Vector2,
+        target: Vector2,
+        rotation: f32,
+        zoom: f32,
+    ) -> Self {
+        Self { offset, target, rotation, zoom }
+    }
+
+    /// the world position drawn at screen position `point`
+    pub fn screen_to_world(&self, point: Vector2) -> Vector2 {
+        let x = (point.x - self.offset.x) / self.zoom;
+        let y = (point.y - self.offset.y) / self.zoom;
+        let (sin, cos) = (-self.rotation.to_radians()).sin_cos();
+        Vector2 {
+            x: self.target.x + x * cos - y * sin,
+            y: self.target.y + x * sin + y * cos,
+        }
+    }
+
+    /// the screen position where world position `point` is drawn
+    pub fn world_to_screen(&self, point: Vector2) -> Vector2 {
+        let x = point.x - self.target.x;
+        let y = point.y - self.target.y;
+        let (sin, cos) = self.rotation.to_radians().sin_cos();
+        Vector2 {
+            x: self.offset.x + (x * cos - y * sin) * self.zoom,
+            y: self.offset.y + (x * sin + y * cos) * self.zoom,
+        }
+    }
+
+    /// move the target just enough to keep `position` within `dead_zone` of
+    /// it, where `dead_zone` holds the half width and height of the zone in
+    /// world units
+    pub fn follow(&mut self, position: Vector2, dead_zone: Vector2) {
+        let follow_axis = |target: &mut f32, position: f32, half: f32| {
+            if position > *target + half {
+                *target = position - half;
+            } else if position < *target - half {
+                *target = position + half;
+            }
+        };
+        follow_axis(&mut self.target.x, position.x, dead_zone.x);
+        follow_axis(&mut self.target.y, position.y, dead_zone.y);
+    }
+
+    /// move the target so that the visible area of a `screen_size` viewport
+    /// stays inside `bounds`, centering on `bounds` along any axis where the
+    /// view is larger. Rotation is ignored
+    pub fn clamp_to_bounds(&mut self, bounds: Rectangle, screen_size: Vector2) {
+        let clamp_axis = |target: &mut f32, offset, screen, start, size| {
+            let before = offset / self.zoom;
+            let after = (screen - offset) / self.zoom;
+            if before + after >= size {
+                *target = start + size / 2.0 + (before - after) / 2.0;
+            } else {
+                *target = target.clamp(start + before, start + size - after);
+            }
+        };
+        clamp_axis(
+            &mut self.target.x,
+            self.offset.x,
+            screen_size.x,
+            bounds.x,
+            bounds.width,
+        );
+        clamp_axis(
+            &mut self.target.y,
+            self.offset.y,
+            screen_size.y,
+            bounds.y,
+            bounds.height,
+        );
+    }
+
+    /// change the zoom to `zoom` while keeping the world position under the
+    /// screen position `point` (typically the mouse cursor) in place
+    pub fn zoom_at(&mut self, point: Vector2, zoom: f32) {
+        let anchor = self.screen_to_world(point);
+        self.zoom = zoom;
+        let moved = self.screen_to_world(point);
+        self.target.x += anchor.x - moved.x;
+        self.target.y += anchor.y - moved.y;
+    }
+
+    /// ease the zoom toward `zoom` around `point` as in
+    /// [Camera2D::zoom_at]. `smoothing` is the time in seconds to cover about
+    /// 63% of the difference, and `dt` the time since the last call
+    pub fn smooth_zoom_at(
+        &mut self,
+        point: Vector2,
+        zoom: f32,
+        smoothing: f32,
+        dt: f32,
+    ) {
+        let t = if smoothing > 0.0 {
+            1.0 - (-dt / smoothing).exp()
+        } else {
+            1.0
+        };
+        self.zoom_at(point, self.zoom + (zoom - self.zoom) * t);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: Vector2, b: Vector2) -> bool {
+        (a.x - b.x).abs() < 1e-4 && (a.y - b.y).abs() < 1e-4
+    }
+
+    fn camera() -> Camera2D {
+        Camera2D::new(
+            Vector2::new(400.0, 300.0),
+            Vector2::new(10.0, 20.0),
+            30.0,
+            2.0,
+        )
+    }
+
+    #[test]
+    fn screen_world_round_trip() {
+        let camera = camera();
+        assert!(close(camera.world_to_screen(camera.target), camera.offset));
+        let p = Vector2::new(123.0, 456.0);
+        assert!(close(camera.world_to_screen(camera.screen_to_world(p)), p));
+    }
+
+    #[test]
+    fn follow_dead_zone() {
+        let mut camera = camera();
+        let zone = Vector2::new(5.0, 5.0);
+        camera.follow(Vector2::new(14.0, 20.0), zone);
+        assert!(close(camera.target, Vector2::new(10.0, 20.0)));
+        camera.follow(Vector2::new(18.0, 10.0), zone);
+        assert!(close(camera.target, Vector2::new(13.0, 15.0)));
+    }
+
+    #[test]
+    fn clamp() {
+        let mut camera = Camera2D::new(
+            Vector2::new(400.0, 300.0),
+            Vector2::new(-100.0, 1000.0),
+            0.0,
+            1.0,
+        );
+        let screen = Vector2::new(800.0, 600.0);
+        // wide enough horizontally, too short vertically
+        let bounds = Rectangle::new(0.0, 0.0, 2000.0, 500.0);
+        camera.clamp_to_bounds(bounds, screen);
+        assert!(close(camera.target, Vector2::new(400.0, 250.0)));
+    }
+
+    #[test]
+    fn zoom_keeps_point() {
+        let mut camera = camera();
+        let cursor = Vector2::new(700.0, 100.0);
+        let before = camera.screen_to_world(cursor);
+        camera.zoom_at(cursor, 5.0);
+        assert_eq!(camera.zoom, 5.0);
+        assert!(close(camera.screen_to_world(cursor), before));
+        camera.smooth_zoom_at(cursor, 1.0, 0.1, 0.05);
+        assert!(camera.zoom > 1.0 && camera.zoom < 5.0);
+        assert!(close(camera.screen_to_world(cursor), before));
+    }
+}
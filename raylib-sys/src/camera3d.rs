@@ -1,7 +1,8 @@
 use crate::{
-    Camera3D, CameraMoveForward, CameraMoveRight, CameraMoveUp, CameraPitch,
+    BoundingBox, Camera3D, CameraMoveForward, CameraMoveRight,
+    CameraMoveToTarget, CameraMoveUp, CameraPitch,
     CameraProjection_CAMERA_ORTHOGRAPHIC, CameraProjection_CAMERA_PERSPECTIVE,
-    CameraYaw, Vector3,
+    CameraRoll, CameraYaw, GetCameraUp, Vector3,
 };
 
 #[repr(i32)]
@@ -54,4 +55,88 @@ impl Camera3D {
     pub fn move_forward(&mut self, distance: f32, moveInWorldPlane: bool) {
         unsafe { CameraMoveForward(self, distance, moveInWorldPlane) }
     }
+
+    pub fn roll(&mut self, angle: f32) {
+        unsafe { CameraRoll(self, angle) }
+    }
+
+    /// change the distance from the target by `delta`, moving away from it if
+    /// `delta` is positive
+    pub fn move_to_target(&mut self, delta: f32) {
+        unsafe { CameraMoveToTarget(self, delta) }
+    }
+
+    /// the normalized up vector of the camera
+    pub fn up(&self) -> Vector3 {
+        let mut camera = *self;
+        unsafe { GetCameraUp(&mut camera) }
+    }
+
+    /// Move the camera along its current view direction and point it at the
+    /// center of `bounds` so that the whole box is visible in a viewport of
+    /// width / height `aspect`. An orthographic camera also has its view
+    /// height adjusted
+    pub fn fit_bounds(&mut self, bounds: BoundingBox, aspect: f32) {
+        let center = bounds.center();
+        let radius = bounds.extents().length();
+        let mut direction = (self.position - self.target).normalize();
+        if direction.length() == 0.0 {
+            direction = Vector3::new(0.0, 0.0, 1.0);
+        }
+        let distance =
+            if self.projection == CameraProjection::Orthographic as i32 {
+                self.fovy = 2.0 * radius * (1.0 / aspect).max(1.0);
+                2.0 * radius
+            } else {
+                let half_fovy = self.fovy.to_radians() / 2.0;
+                let half_fovx = (half_fovy.tan() * aspect).atan();
+                radius / half_fovy.min(half_fovx).sin()
+            };
+        self.target = center;
+        self.position = center + direction.scale(distance);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unit_box() -> BoundingBox {
+        BoundingBox {
+            min: Vector3::new(-1.0, -1.0, -1.0),
+            max: Vector3::new(1.0, 1.0, 1.0),
+        }
+    }
+
+    fn camera(projection: CameraProjection) -> Camera3D {
+        Camera3D::new(
+            Vector3::new(0.0, 0.0, 10.0),
+            Vector3::new(3.0, 0.0, 0.0),
+            Vector3::new(0.0, 1.0, 0.0),
+            90.0,
+            projection,
+        )
+    }
+
+    #[test]
+    fn fit_perspective() {
+        let mut camera = camera(CameraProjection::Perspective);
+        camera.fit_bounds(unit_box(), 1.0);
+        let want = 3.0f32.sqrt() / std::f32::consts::FRAC_PI_4.sin();
+        assert_eq!(camera.target.length(), 0.0);
+        assert!((camera.position.length() - want).abs() < 1e-5);
+
+        // a narrow viewport is limited by the horizontal field of view
+        camera.fit_bounds(unit_box(), 0.5);
+        assert!(camera.position.length() > want);
+    }
+
+    #[test]
+    fn fit_orthographic() {
+        let mut camera = camera(CameraProjection::Orthographic);
+        camera.fit_bounds(unit_box(), 2.0);
+        assert!((camera.fovy - 2.0 * 3.0f32.sqrt()).abs() < 1e-5);
+        camera.fit_bounds(unit_box(), 0.5);
+        assert!((camera.fovy - 4.0 * 3.0f32.sqrt()).abs() < 1e-5);
+    }
 }
@@ -0,0 +1,232 @@
+//! 2D collision checks, ported from raylib's `rshapes.c` so they work without
+//! an open window. Rectangle checks are methods on [Rectangle].
+
+use crate::{Rectangle, Vector2};
+
+/// whether two circles overlap or touch, like `CheckCollisionCircles`
+pub fn circles(
+    center1: Vector2,
+    radius1: f32,
+    center2: Vector2,
+    radius2: f32,
+) -> bool {
+    let radius_sum = radius1 + radius2;
+    center1.distance_sqr(center2) <= radius_sum * radius_sum
+}
+
+/// whether a circle and a rectangle overlap or touch, like
+/// `CheckCollisionCircleRec`
+pub fn circle_rec(center: Vector2, radius: f32, rec: Rectangle) -> bool {
+    let half = rec.size().scale(0.5);
+    let dx = (center.x - (rec.x + half.x)).abs();
+    let dy = (center.y - (rec.y + half.y)).abs();
+    if dx > half.x + radius || dy > half.y + radius {
+        return false;
+    }
+    if dx <= half.x || dy <= half.y {
+        return true;
+    }
+    let corner = Vector2::new(dx - half.x, dy - half.y);
+    corner.length_sqr() <= radius * radius
+}
+
+/// whether a circle touches the line segment from `p1` to `p2`, like
+/// `CheckCollisionCircleLine`
+pub fn circle_line(
+    center: Vector2,
+    radius: f32,
+    p1: Vector2,
+    p2: Vector2,
+) -> bool {
+    closest_on_segment(center, p1, p2).distance_sqr(center) <= radius * radius
+}
+
+/// whether `point` is inside the circle or on its edge, like
+/// `CheckCollisionPointCircle`
+pub fn point_circle(point: Vector2, center: Vector2, radius: f32) -> bool {
+    circles(point, 0.0, center, radius)
+}
+
+/// whether `point` is strictly inside the triangle, in either winding order,
+/// like `CheckCollisionPointTriangle`
+pub fn point_triangle(
+    point: Vector2,
+    p1: Vector2,
+    p2: Vector2,
+    p3: Vector2,
+) -> bool {
+    let den = (p2.y - p3.y) * (p1.x - p3.x) + (p3.x - p2.x) * (p1.y - p3.y);
+    let alpha = ((p2.y - p3.y) * (point.x - p3.x)
+        + (p3.x - p2.x) * (point.y - p3.y))
+        / den;
+    let beta = ((p3.y - p1.y) * (point.x - p3.x)
+        + (p1.x - p3.x) * (point.y - p3.y))
+        / den;
+    let gamma = 1.0 - alpha - beta;
+    alpha > 0.0 && beta > 0.0 && gamma > 0.0
+}
+
+/// whether `point` is within `threshold` of the line segment from `p1` to
+/// `p2`, like `CheckCollisionPointLine`
+pub fn point_line(
+    point: Vector2,
+    p1: Vector2,
+    p2: Vector2,
+    threshold: f32,
+) -> bool {
+    circle_line(point, threshold, p1, p2)
+}
+
+/// whether `point` is inside the polygon with vertices `points`, using the
+/// even-odd rule like `CheckCollisionPointPoly`
+pub fn point_poly(point: Vector2, points: &[Vector2]) -> bool {
+    if points.len() < 3 {
+        return false;
+    }
+    let mut inside = false;
+    let mut j = points.len() - 1;
+    for (i, a) in points.iter().enumerate() {
+        let b = points[j];
+        if (a.y > point.y) != (b.y > point.y)
+            && point.x < (b.x - a.x) * (point.y - a.y) / (b.y - a.y) + a.x
+        {
+            inside = !inside;
+        }
+        j = i;
+    }
+    inside
+}
+
+/// where the line segments `start1`-`end1` and `start2`-`end2` cross, like
+/// `CheckCollisionLines`. Parallel segments never cross
+pub fn lines(
+    start1: Vector2,
+    end1: Vector2,
+    start2: Vector2,
+    end2: Vector2,
+) -> Option<Vector2> {
+    let div = (end2.y - start2.y) * (end1.x - start1.x)
+        - (end2.x - start2.x) * (end1.y - start1.y);
+    if div.abs() < f32::EPSILON {
+        return None;
+    }
+
+    let cross1 = start1.x * end1.y - start1.y * end1.x;
+    let cross2 = start2.x * end2.y - start2.y * end2.x;
+    let point = Vector2::new(
+        ((start2.x - end2.x) * cross1 - (start1.x - end1.x) * cross2) / div,
+        ((start2.y - end2.y) * cross1 - (start1.y - end1.y) * cross2) / div,
+    );
+
+    // the lines cross, but the point also has to be on both segments. the
+    // range is only checked along axes the segment is not flat in, so
+    // rounding does not reject points on horizontal or vertical segments
+    let within = |a: f32, b: f32, x: f32| {
+        (a - b).abs() <= f32::EPSILON || (a.min(b) <= x && x <= a.max(b))
+    };
+    let on_segments = within(start1.x, end1.x, point.x)
+        && within(start2.x, end2.x, point.x)
+        && within(start1.y, end1.y, point.y)
+        && within(start2.y, end2.y, point.y);
+    on_segments.then_some(point)
+}
+
+fn closest_on_segment(point: Vector2, p1: Vector2, p2: Vector2) -> Vector2 {
+    let line = p2 - p1;
+    let length_sqr = line.length_sqr();
+    if length_sqr <= f32::EPSILON {
+        return p1;
+    }
+    let t = ((point - p1).dot(line) / length_sqr).clamp(0.0, 1.0);
+    p1 + line.scale(t)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn v(x: f32, y: f32) -> Vector2 {
+        Vector2::new(x, y)
+    }
+
+    #[test]
+    fn circles_touching() {
+        assert!(circles(v(0.0, 0.0), 1.0, v(3.0, 0.0), 2.0));
+        assert!(!circles(v(0.0, 0.0), 1.0, v(3.0, 0.1), 2.0));
+        assert!(point_circle(v(0.6, 0.8), v(0.0, 0.0), 1.0));
+        assert!(!point_circle(v(0.7, 0.8), v(0.0, 0.0), 1.0));
+    }
+
+    #[test]
+    fn circle_and_rectangle() {
+        let rec = Rectangle::new(0.0, 0.0, 4.0, 2.0);
+        // along an edge
+        assert!(circle_rec(v(5.0, 1.0), 1.0, rec));
+        assert!(!circle_rec(v(5.1, 1.0), 1.0, rec));
+        // off a corner the distance is diagonal
+        assert!(!circle_rec(v(4.8, 2.8), 1.0, rec));
+        assert!(circle_rec(v(4.6, 2.6), 1.0, rec));
+        // fully inside
+        assert!(circle_rec(v(2.0, 1.0), 0.1, rec));
+    }
+
+    #[test]
+    fn circle_and_segment() {
+        let (a, b) = (v(0.0, 0.0), v(4.0, 0.0));
+        assert!(circle_line(v(2.0, 1.0), 1.0, a, b));
+        assert!(!circle_line(v(2.0, 1.1), 1.0, a, b));
+        // past the end the distance is to the end point
+        assert!(!circle_line(v(4.8, 0.8), 1.0, a, b));
+        assert!(circle_line(v(4.5, 0.5), 1.0, a, b));
+        // a degenerate segment is a point
+        assert!(circle_line(v(0.0, 0.5), 1.0, a, a));
+
+        assert!(point_line(v(2.0, 0.05), a, b, 0.1));
+        assert!(!point_line(v(2.0, 0.2), a, b, 0.1));
+    }
+
+    #[test]
+    fn triangle() {
+        let (a, b, c) = (v(0.0, 0.0), v(4.0, 0.0), v(0.0, 4.0));
+        assert!(point_triangle(v(1.0, 1.0), a, b, c));
+        assert!(point_triangle(v(1.0, 1.0), a, c, b));
+        assert!(!point_triangle(v(3.0, 3.0), a, b, c));
+        // edges are outside
+        assert!(!point_triangle(v(2.0, 0.0), a, b, c));
+    }
+
+    #[test]
+    fn polygon() {
+        // a U shape, open at the top
+        let points = [
+            v(0.0, 0.0),
+            v(3.0, 0.0),
+            v(3.0, 3.0),
+            v(2.0, 3.0),
+            v(2.0, 1.0),
+            v(1.0, 1.0),
+            v(1.0, 3.0),
+            v(0.0, 3.0),
+        ];
+        assert!(point_poly(v(0.5, 2.0), &points));
+        assert!(point_poly(v(1.5, 0.5), &points));
+        assert!(!point_poly(v(1.5, 2.0), &points));
+        assert!(!point_poly(v(4.0, 1.0), &points));
+        assert!(!point_poly(v(0.5, 0.5), &points[..2]));
+    }
+
+    #[test]
+    fn segments() {
+        let got = lines(v(0.0, 0.0), v(4.0, 4.0), v(0.0, 4.0), v(4.0, 0.0));
+        assert_eq!(got, Some(v(2.0, 2.0)));
+        // axis-aligned segments
+        let got = lines(v(0.0, 1.0), v(4.0, 1.0), v(3.0, 0.0), v(3.0, 5.0));
+        assert_eq!(got, Some(v(3.0, 1.0)));
+        // the lines cross, but past the end of the second segment
+        let got = lines(v(0.0, 0.0), v(4.0, 4.0), v(0.0, 4.0), v(1.0, 3.0));
+        assert_eq!(got, None);
+        // parallel
+        let got = lines(v(0.0, 0.0), v(4.0, 0.0), v(0.0, 1.0), v(4.0, 1.0));
+        assert_eq!(got, None);
+    }
+}
@@ -0,0 +1,408 @@
+//! Conversions between the raylib math types and those of other math crates,
+//! each behind the cargo feature of the same name. Matrices keep their
+//! meaning rather than their layout, so a translation stays a translation.
+//! Colors convert to and from vectors of `u8` in `r, g, b, a` order.
+
+#[cfg(feature = "glam")]
+mod glam {
+    use crate::{Color, Matrix, Quaternion, Vector2, Vector3, Vector4};
+
+    impl From<glam::Vec2> for Vector2 {
+        fn from(v: glam::Vec2) -> Self {
+            Self { x: v.x, y: v.y }
+        }
+    }
+
+    impl From<Vector2> for glam::Vec2 {
+        fn from(v: Vector2) -> Self {
+            Self::new(v.x, v.y)
+        }
+    }
+
+    impl From<glam::Vec3> for Vector3 {
+        fn from(v: glam::Vec3) -> Self {
+            Self { x: v.x, y: v.y, z: v.z }
+        }
+    }
+
+    impl From<Vector3> for glam::Vec3 {
+        fn from(v: Vector3) -> Self {
+            Self::new(v.x, v.y, v.z)
+        }
+    }
+
+    impl From<glam::Vec3A> for Vector3 {
+        fn from(v: glam::Vec3A) -> Self {
+            Self { x: v.x, y: v.y, z: v.z }
+        }
+    }
+
+    impl From<Vector3> for glam::Vec3A {
+        fn from(v: Vector3) -> Self {
+            Self::new(v.x, v.y, v.z)
+        }
+    }
+
+    impl From<glam::Vec4> for Vector4 {
+        fn from(v: glam::Vec4) -> Self {
+            Self { x: v.x, y: v.y, z: v.z, w: v.w }
+        }
+    }
+
+    impl From<Vector4> for glam::Vec4 {
+        fn from(v: Vector4) -> Self {
+            Self::new(v.x, v.y, v.z, v.w)
+        }
+    }
+
+    impl From<glam::Quat> for Quaternion {
+        fn from(q: glam::Quat) -> Self {
+            Self { x: q.x, y: q.y, z: q.z, w: q.w }
+        }
+    }
+
+    impl From<Quaternion> for glam::Quat {
+        fn from(q: Quaternion) -> Self {
+            Self::from_xyzw(q.x, q.y, q.z, q.w)
+        }
+    }
+
+    impl From<glam::Mat4> for Matrix {
+        fn from(mat: glam::Mat4) -> Self {
+            mat.to_cols_array().into()
+        }
+    }
+
+    impl From<Matrix> for glam::Mat4 {
+        fn from(mat: Matrix) -> Self {
+            Self::from_cols_array(&mat.into())
+        }
+    }
+
+    impl From<glam::U8Vec4> for Color {
+        fn from(c: glam::U8Vec4) -> Self {
+            Self { r: c.x, g: c.y, b: c.z, a: c.w }
+        }
+    }
+
+    impl From<Color> for glam::U8Vec4 {
+        fn from(c: Color) -> Self {
+            Self::new(c.r, c.g, c.b, c.a)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn round_trip() {
+            let v = Vector3::new(1.0, 2.0, 3.0);
+            assert_eq!(glam::Vec3::from(v), glam::vec3(1.0, 2.0, 3.0));
+            assert_eq!(Vector3::from(glam::Vec3::from(v)), v);
+
+            let transform =
+                Matrix::rotate_x(0.5) * Matrix::translate(1.0, 2.0, 3.0);
+            let mat = glam::Mat4::from(transform);
+            assert_eq!(mat.w_axis, glam::vec4(1.0, 2.0, 3.0, 1.0));
+            assert_eq!(Matrix::from(mat), transform);
+            let p = Vector3::new(0.0, 1.0, 0.0);
+            let got = mat.transform_point3(p.into());
+            assert!(
+                (Vector3::from(got) - p.transform(transform)).length() < 1e-6
+            );
+
+            let q = Quaternion::from_axis_angle(v, 0.7);
+            assert_eq!(Quaternion::from(glam::Quat::from(q)), q);
+            let got = glam::Quat::from(q) * glam::Vec3::X;
+            assert!(
+                (Vector3::from(got) - q * Vector3::new(1.0, 0.0, 0.0)).length()
+                    < 1e-6
+            );
+
+            let c = Color { r: 1, g: 2, b: 3, a: 4 };
+            assert_eq!(glam::U8Vec4::from(c), glam::U8Vec4::new(1, 2, 3, 4));
+        }
+    }
+}
+
+#[cfg(feature = "mint")]
+mod mint {
+    use crate::{Color, Matrix, Quaternion, Vector2, Vector3, Vector4};
+
+    impl From<mint::Vector2<f32>> for Vector2 {
+        fn from(v: mint::Vector2<f32>) -> Self {
+            Self { x: v.x, y: v.y }
+        }
+    }
+
+    impl From<Vector2> for mint::Vector2<f32> {
+        fn from(v: Vector2) -> Self {
+            Self { x: v.x, y: v.y }
+        }
+    }
+
+    impl From<mint::Point2<f32>> for Vector2 {
+        fn from(v: mint::Point2<f32>) -> Self {
+            Self { x: v.x, y: v.y }
+        }
+    }
+
+    impl From<Vector2> for mint::Point2<f32> {
+        fn from(v: Vector2) -> Self {
+            Self { x: v.x, y: v.y }
+        }
+    }
+
+    impl From<mint::Vector3<f32>> for Vector3 {
+        fn from(v: mint::Vector3<f32>) -> Self {
+            Self { x: v.x, y: v.y, z: v.z }
+        }
+    }
+
+    impl From<Vector3> for mint::Vector3<f32> {
+        fn from(v: Vector3) -> Self {
+            Self { x: v.x, y: v.y, z: v.z }
+        }
+    }
+
+    impl From<mint::Point3<f32>> for Vector3 {
+        fn from(v: mint::Point3<f32>) -> Self {
+            Self { x: v.x, y: v.y, z: v.z }
+        }
+    }
+
+    impl From<Vector3> for mint::Point3<f32> {
+        fn from(v: Vector3) -> Self {
+            Self { x: v.x, y: v.y, z: v.z }
+        }
+    }
+
+    impl From<mint::Vector4<f32>> for Vector4 {
+        fn from(v: mint::Vector4<f32>) -> Self {
+            Self { x: v.x, y: v.y, z: v.z, w: v.w }
+        }
+    }
+
+    impl From<Vector4> for mint::Vector4<f32> {
+        fn from(v: Vector4) -> Self {
+            Self { x: v.x, y: v.y, z: v.z, w: v.w }
+        }
+    }
+
+    impl From<mint::Quaternion<f32>> for Quaternion {
+        fn from(q: mint::Quaternion<f32>) -> Self {
+            Self {
+                x: q.v.x,
+                y: q.v.y,
+                z: q.v.z,
+                w: q.s,
+            }
+        }
+    }
+
+    impl From<Quaternion> for mint::Quaternion<f32> {
+        fn from(q: Quaternion) -> Self {
+            Self {
+                v: mint::Vector3 { x: q.x, y: q.y, z: q.z },
+                s: q.w,
+            }
+        }
+    }
+
+    impl From<mint::ColumnMatrix4<f32>> for Matrix {
+        fn from(mat: mint::ColumnMatrix4<f32>) -> Self {
+            let m: [f32; 16] = mat.into();
+            m.into()
+        }
+    }
+
+    impl From<Matrix> for mint::ColumnMatrix4<f32> {
+        fn from(mat: Matrix) -> Self {
+            <[f32; 16]>::from(mat).into()
+        }
+    }
+
+    impl From<mint::Vector4<u8>> for Color {
+        fn from(c: mint::Vector4<u8>) -> Self {
+            Self { r: c.x, g: c.y, b: c.z, a: c.w }
+        }
+    }
+
+    impl From<Color> for mint::Vector4<u8> {
+        fn from(c: Color) -> Self {
+            Self { x: c.r, y: c.g, z: c.b, w: c.a }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn round_trip() {
+            let v = Vector3::new(1.0, 2.0, 3.0);
+            let got: mint::Vector3<f32> = v.into();
+            assert_eq!(got, mint::Vector3 { x: 1.0, y: 2.0, z: 3.0 });
+            assert_eq!(Vector3::from(got), v);
+
+            let transform =
+                Matrix::rotate_x(0.5) * Matrix::translate(1.0, 2.0, 3.0);
+            let mat = mint::ColumnMatrix4::from(transform);
+            assert_eq!(mat.w, mint::Vector4 { x: 1.0, y: 2.0, z: 3.0, w: 1.0 });
+            assert_eq!(Matrix::from(mat), transform);
+
+            let q = Quaternion::from_axis_angle(v, 0.7);
+            let got = mint::Quaternion::from(q);
+            assert_eq!(got.s, q.w);
+            assert_eq!(Quaternion::from(got), q);
+        }
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+mod nalgebra {
+    use nalgebra as na;
+
+    use crate::{Color, Matrix, Quaternion, Vector2, Vector3, Vector4};
+
+    impl From<na::Vector2<f32>> for Vector2 {
+        fn from(v: na::Vector2<f32>) -> Self {
+            Self { x: v.x, y: v.y }
+        }
+    }
+
+    impl From<Vector2> for na::Vector2<f32> {
+        fn from(v: Vector2) -> Self {
+            Self::new(v.x, v.y)
+        }
+    }
+
+    impl From<na::Point2<f32>> for Vector2 {
+        fn from(p: na::Point2<f32>) -> Self {
+            Self { x: p.x, y: p.y }
+        }
+    }
+
+    impl From<Vector2> for na::Point2<f32> {
+        fn from(v: Vector2) -> Self {
+            Self::new(v.x, v.y)
+        }
+    }
+
+    impl From<na::Vector3<f32>> for Vector3 {
+        fn from(v: na::Vector3<f32>) -> Self {
+            Self { x: v.x, y: v.y, z: v.z }
+        }
+    }
+
+    impl From<Vector3> for na::Vector3<f32> {
+        fn from(v: Vector3) -> Self {
+            Self::new(v.x, v.y, v.z)
+        }
+    }
+
+    impl From<na::Point3<f32>> for Vector3 {
+        fn from(p: na::Point3<f32>) -> Self {
+            Self { x: p.x, y: p.y, z: p.z }
+        }
+    }
+
+    impl From<Vector3> for na::Point3<f32> {
+        fn from(v: Vector3) -> Self {
+            Self::new(v.x, v.y, v.z)
+        }
+    }
+
+    impl From<na::Vector4<f32>> for Vector4 {
+        fn from(v: na::Vector4<f32>) -> Self {
+            Self { x: v.x, y: v.y, z: v.z, w: v.w }
+        }
+    }
+
+    impl From<Vector4> for na::Vector4<f32> {
+        fn from(v: Vector4) -> Self {
+            Self::new(v.x, v.y, v.z, v.w)
+        }
+    }
+
+    impl From<na::Quaternion<f32>> for Quaternion {
+        fn from(q: na::Quaternion<f32>) -> Self {
+            Self { x: q.i, y: q.j, z: q.k, w: q.w }
+        }
+    }
+
+    impl From<Quaternion> for na::Quaternion<f32> {
+        fn from(q: Quaternion) -> Self {
+            Self::new(q.w, q.x, q.y, q.z)
+        }
+    }
+
+    impl From<na::UnitQuaternion<f32>> for Quaternion {
+        fn from(q: na::UnitQuaternion<f32>) -> Self {
+            q.into_inner().into()
+        }
+    }
+
+    /// normalizes `q`, since raylib does not keep track of unit quaternions
+    impl From<Quaternion> for na::UnitQuaternion<f32> {
+        fn from(q: Quaternion) -> Self {
+            Self::from_quaternion(q.into())
+        }
+    }
+
+    impl From<na::Matrix4<f32>> for Matrix {
+        fn from(mat: na::Matrix4<f32>) -> Self {
+            let mut m = [0.0; 16];
+            m.copy_from_slice(mat.as_slice());
+            m.into()
+        }
+    }
+
+    impl From<Matrix> for na::Matrix4<f32> {
+        fn from(mat: Matrix) -> Self {
+            Self::from_column_slice(&<[f32; 16]>::from(mat))
+        }
+    }
+
+    impl From<na::Vector4<u8>> for Color {
+        fn from(c: na::Vector4<u8>) -> Self {
+            Self { r: c.x, g: c.y, b: c.z, a: c.w }
+        }
+    }
+
+    impl From<Color> for na::Vector4<u8> {
+        fn from(c: Color) -> Self {
+            Self::new(c.r, c.g, c.b, c.a)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn round_trip() {
+            use nalgebra as na;
+
+            let v = Vector3::new(1.0, 2.0, 3.0);
+            assert_eq!(na::Vector3::from(v), na::Vector3::new(1.0, 2.0, 3.0));
+            assert_eq!(Vector3::from(na::Point3::from(v)), v);
+
+            let transform =
+                Matrix::rotate_x(0.5) * Matrix::translate(1.0, 2.0, 3.0);
+            let mat = na::Matrix4::from(transform);
+            assert_eq!(mat[(0, 3)], 1.0);
+            assert_eq!(mat[(1, 3)], 2.0);
+            assert_eq!(Matrix::from(mat), transform);
+
+            let q = Quaternion::from_axis_angle(v, 0.7);
+            let unit = na::UnitQuaternion::from(q);
+            assert!((unit.angle() - 0.7).abs() < 1e-6);
+            assert_eq!(Quaternion::from(na::Quaternion::from(q)), q);
+
+            let c = Color { r: 1, g: 2, b: 3, a: 4 };
+            assert_eq!(Color::from(na::Vector4::new(1, 2, 3, 4)).a, c.a);
+        }
+    }
+}
@@ -13,8 +13,20 @@ pub const CAMERA_PAN_SPEED: f32 = 0.2;
 pub const CAMERA_MOUSE_MOVE_SENSITIVITY: f32 = 0.003;
 pub const CAMERA_MOVE_SPEED: f32 = 0.09;
 
+#[macro_use]
+mod macros;
+
+mod interop;
+#[cfg(test)]
+mod raymath_c;
+
+pub mod bounding_box;
+pub mod camera2d;
 pub mod camera3d;
+pub mod collision;
 pub mod matrix;
+pub mod quaternion;
+pub mod ray;
 pub mod rectangle;
 pub mod vector2;
 pub mod vector3;
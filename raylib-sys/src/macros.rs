@@ -0,0 +1,126 @@
+/// Implement the arithmetic operators, `PartialEq` and `Default` for a vector
+/// type in terms of its raymath functions. Division by a scalar and
+/// equality are component-wise over `fields`
+macro_rules! vector_ops {
+    (
+        $T:ident { $($field:ident),+ },
+        add: $add:ident,
+        add_value: $add_value:ident,
+        subtract: $subtract:ident,
+        subtract_value: $subtract_value:ident,
+        multiply: $multiply:ident,
+        scale: $scale:ident,
+        divide: $divide:ident,
+        negate: $negate:ident $(,)?
+    ) => {
+        impl std::ops::Add<$T> for $T {
+            type Output = $T;
+
+            fn add(self, rhs: $T) -> Self::Output {
+                $add(self, rhs)
+            }
+        }
+
+        impl std::ops::Add<f32> for $T {
+            type Output = $T;
+
+            fn add(self, rhs: f32) -> Self::Output {
+                $add_value(self, rhs)
+            }
+        }
+
+        impl std::ops::Sub<$T> for $T {
+            type Output = $T;
+
+            fn sub(self, rhs: $T) -> Self::Output {
+                $subtract(self, rhs)
+            }
+        }
+
+        impl std::ops::Sub<f32> for $T {
+            type Output = $T;
+
+            fn sub(self, rhs: f32) -> Self::Output {
+                $subtract_value(self, rhs)
+            }
+        }
+
+        impl std::ops::Mul<$T> for $T {
+            type Output = $T;
+
+            fn mul(self, rhs: $T) -> Self::Output {
+                $multiply(self, rhs)
+            }
+        }
+
+        impl std::ops::Mul<f32> for $T {
+            type Output = $T;
+
+            fn mul(self, rhs: f32) -> Self::Output {
+                $scale(self, rhs)
+            }
+        }
+
+        impl std::ops::Mul<$T> for f32 {
+            type Output = $T;
+
+            fn mul(self, rhs: $T) -> Self::Output {
+                $scale(rhs, self)
+            }
+        }
+
+        impl std::ops::Div<$T> for $T {
+            type Output = $T;
+
+            fn div(self, rhs: $T) -> Self::Output {
+                $divide(self, rhs)
+            }
+        }
+
+        impl std::ops::Div<f32> for $T {
+            type Output = $T;
+
+            fn div(self, rhs: f32) -> Self::Output {
+                $T { $($field: self.$field / rhs),+ }
+            }
+        }
+
+        impl std::ops::Neg for $T {
+            type Output = $T;
+
+            fn neg(self) -> Self::Output {
+                $negate(self)
+            }
+        }
+
+        vector_ops!(@assign $T, AddAssign, add_assign, +);
+        vector_ops!(@assign $T, SubAssign, sub_assign, -);
+        vector_ops!(@assign $T, MulAssign, mul_assign, *);
+        vector_ops!(@assign $T, DivAssign, div_assign, /);
+
+        impl PartialEq for $T {
+            fn eq(&self, other: &Self) -> bool {
+                $(self.$field == other.$field)&&+
+            }
+        }
+
+        impl Default for $T {
+            fn default() -> Self {
+                $T { $($field: 0.0),+ }
+            }
+        }
+    };
+    (@assign $T:ident, $trait:ident, $method:ident, $op:tt) => {
+        impl std::ops::$trait<$T> for $T {
+            fn $method(&mut self, rhs: $T) {
+                *self = *self $op rhs;
+            }
+        }
+
+        impl std::ops::$trait<f32> for $T {
+            fn $method(&mut self, rhs: f32) {
+                *self = *self $op rhs;
+            }
+        }
+    };
+}
@@ -1,6 +1,207 @@
-use std::ffi::c_float;
+use std::{
+    ffi::c_float,
+    fmt,
+    ops::{Add, Mul, Sub},
+};
 
-use crate::{Matrix, Vector3};
+use crate::{
+    quaternion::QuaternionFromMatrix, Matrix, Quaternion, Vector3, EPSILON,
+};
+
+impl Matrix {
+    pub fn identity() -> Self {
+        MatrixIdentity()
+    }
+
+    pub fn translate(x: f32, y: f32, z: f32) -> Self {
+        MatrixTranslate(x, y, z)
+    }
+
+    pub fn scale(x: f32, y: f32, z: f32) -> Self {
+        MatrixScale(x, y, z)
+    }
+
+    /// rotation of `angle` radians around `axis`
+    pub fn rotate(axis: Vector3, angle: f32) -> Self {
+        MatrixRotate(axis, angle)
+    }
+
+    pub fn rotate_x(angle: f32) -> Self {
+        MatrixRotateX(angle)
+    }
+
+    pub fn rotate_y(angle: f32) -> Self {
+        MatrixRotateY(angle)
+    }
+
+    pub fn rotate_z(angle: f32) -> Self {
+        MatrixRotateZ(angle)
+    }
+
+    /// rotation by the components of `angle` in radians, the same as
+    /// `rotate_z(angle.z) * rotate_y(angle.y) * rotate_x(angle.x)`
+    pub fn rotate_xyz(angle: Vector3) -> Self {
+        MatrixRotateXYZ(angle)
+    }
+
+    /// rotation by the components of `angle` in radians, the same as
+    /// `rotate_x(angle.x) * rotate_y(angle.y) * rotate_z(angle.z)`
+    pub fn rotate_zyx(angle: Vector3) -> Self {
+        MatrixRotateZYX(angle)
+    }
+
+    /// view matrix for a camera at `eye` looking at `target`
+    pub fn look_at(eye: Vector3, target: Vector3, up: Vector3) -> Self {
+        MatrixLookAt(eye, target, up)
+    }
+
+    /// perspective projection with vertical field of view `fovy` in radians
+    pub fn perspective(fovy: f64, aspect: f64, near: f64, far: f64) -> Self {
+        MatrixPerspective(fovy, aspect, near, far)
+    }
+
+    pub fn ortho(
+        left: f64,
+        right: f64,
+        bottom: f64,
+        top: f64,
+        near: f64,
+        far: f64,
+    ) -> Self {
+        MatrixOrtho(left, right, bottom, top, near, far)
+    }
+
+    pub fn frustum(
+        left: f64,
+        right: f64,
+        bottom: f64,
+        top: f64,
+        near: f64,
+        far: f64,
+    ) -> Self {
+        MatrixFrustum(left, right, bottom, top, near, far)
+    }
+
+    pub fn invert(self) -> Self {
+        MatrixInvert(self)
+    }
+
+    pub fn transpose(self) -> Self {
+        MatrixTranspose(self)
+    }
+
+    pub fn determinant(self) -> f32 {
+        MatrixDeterminant(self)
+    }
+
+    pub fn trace(self) -> f32 {
+        MatrixTrace(self)
+    }
+
+    /// split a matrix built as scale * rotation * translation back into
+    /// translation, rotation and scale, discarding any shear
+    pub fn decompose(self) -> (Vector3, Quaternion, Vector3) {
+        MatrixDecompose(self)
+    }
+}
+
+/// Multiplication follows raymath, so `a * b` applies `a` first and then `b`
+impl Mul<Matrix> for Matrix {
+    type Output = Matrix;
+
+    fn mul(self, rhs: Matrix) -> Self::Output {
+        MatrixMultiply(self, rhs)
+    }
+}
+
+impl Add<Matrix> for Matrix {
+    type Output = Matrix;
+
+    fn add(self, rhs: Matrix) -> Self::Output {
+        MatrixAdd(self, rhs)
+    }
+}
+
+impl Sub<Matrix> for Matrix {
+    type Output = Matrix;
+
+    fn sub(self, rhs: Matrix) -> Self::Output {
+        MatrixSubtract(self, rhs)
+    }
+}
+
+impl PartialEq for Matrix {
+    fn eq(&self, other: &Self) -> bool {
+        <[f32; 16]>::from(*self) == <[f32; 16]>::from(*other)
+    }
+}
+
+impl Default for Matrix {
+    fn default() -> Self {
+        MatrixIdentity()
+    }
+}
+
+/// The elements in column-major order, as OpenGL expects (the same as
+/// raymath's `MatrixToFloatV`)
+impl From<Matrix> for [f32; 16] {
+    fn from(mat: Matrix) -> Self {
+        #[rustfmt::skip]
+        let Matrix {
+            m0, m4, m8, m12,
+            m1, m5, m9, m13,
+            m2, m6, m10, m14,
+            m3, m7, m11, m15,
+        } = mat;
+        [
+            m0, m1, m2, m3, m4, m5, m6, m7, m8, m9, m10, m11, m12, m13, m14,
+            m15,
+        ]
+    }
+}
+
+/// Build from elements in column-major order, as from [MatrixToFloatV]
+impl From<[f32; 16]> for Matrix {
+    fn from(m: [f32; 16]) -> Self {
+        #[rustfmt::skip]
+        let [
+            m0, m1, m2, m3,
+            m4, m5, m6, m7,
+            m8, m9, m10, m11,
+            m12, m13, m14, m15,
+        ] = m;
+        #[rustfmt::skip]
+        let mat = Matrix {
+            m0, m4, m8, m12,
+            m1, m5, m9, m13,
+            m2, m6, m10, m14,
+            m3, m7, m11, m15,
+        };
+        mat
+    }
+}
+
+/// Prints the rows of the matrix as a grid, with 4 decimal places unless a
+/// precision is given
+impl fmt::Debug for Matrix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let m = <[f32; 16]>::from(*self);
+        let p = f.precision().unwrap_or(4);
+        writeln!(f, "Matrix [")?;
+        for row in 0..4 {
+            writeln!(
+                f,
+                "    [{:>w$.p$}, {:>w$.p$}, {:>w$.p$}, {:>w$.p$}],",
+                m[row],
+                m[row + 4],
+                m[row + 8],
+                m[row + 12],
+                w = p + 6,
+            )?;
+        }
+        write!(f, "]")
+    }
+}
 
 #[rustfmt::skip]
 pub fn MatrixZero() -> Matrix {
@@ -12,6 +213,139 @@ pub fn MatrixZero() -> Matrix {
     }
 }
 
+#[rustfmt::skip]
+pub fn MatrixIdentity() -> Matrix {
+    Matrix {
+        m0: 1.0, m4: 0.0, m8: 0.0, m12: 0.0,
+        m1: 0.0, m5: 1.0, m9: 0.0, m13: 0.0,
+        m2: 0.0, m6: 0.0, m10: 1.0, m14: 0.0,
+        m3: 0.0, m7: 0.0, m11: 0.0, m15: 1.0,
+    }
+}
+
+pub fn MatrixDeterminant(mat: Matrix) -> f32 {
+    #[rustfmt::skip]
+    let [
+        a00, a01, a02, a03,
+        a10, a11, a12, a13,
+        a20, a21, a22, a23,
+        a30, a31, a32, a33,
+    ]: [f32; 16] = mat.into();
+    a30 * a21 * a12 * a03 - a20 * a31 * a12 * a03 - a30 * a11 * a22 * a03
+        + a10 * a31 * a22 * a03
+        + a20 * a11 * a32 * a03
+        - a10 * a21 * a32 * a03
+        - a30 * a21 * a02 * a13
+        + a20 * a31 * a02 * a13
+        + a30 * a01 * a22 * a13
+        - a00 * a31 * a22 * a13
+        - a20 * a01 * a32 * a13
+        + a00 * a21 * a32 * a13
+        + a30 * a11 * a02 * a23
+        - a10 * a31 * a02 * a23
+        - a30 * a01 * a12 * a23
+        + a00 * a31 * a12 * a23
+        + a10 * a01 * a32 * a23
+        - a00 * a11 * a32 * a23
+        - a20 * a11 * a02 * a33
+        + a10 * a21 * a02 * a33
+        + a20 * a01 * a12 * a33
+        - a00 * a21 * a12 * a33
+        - a10 * a01 * a22 * a33
+        + a00 * a11 * a22 * a33
+}
+
+pub fn MatrixTrace(mat: Matrix) -> f32 {
+    mat.m0 + mat.m5 + mat.m10 + mat.m15
+}
+
+#[rustfmt::skip]
+pub fn MatrixTranspose(mat: Matrix) -> Matrix {
+    Matrix {
+        m0: mat.m0, m4: mat.m1, m8: mat.m2, m12: mat.m3,
+        m1: mat.m4, m5: mat.m5, m9: mat.m6, m13: mat.m7,
+        m2: mat.m8, m6: mat.m9, m10: mat.m10, m14: mat.m11,
+        m3: mat.m12, m7: mat.m13, m11: mat.m14, m15: mat.m15,
+    }
+}
+
+pub fn MatrixInvert(mat: Matrix) -> Matrix {
+    #[rustfmt::skip]
+    let [
+        a00, a01, a02, a03,
+        a10, a11, a12, a13,
+        a20, a21, a22, a23,
+        a30, a31, a32, a33,
+    ]: [f32; 16] = mat.into();
+
+    let b00 = a00 * a11 - a01 * a10;
+    let b01 = a00 * a12 - a02 * a10;
+    let b02 = a00 * a13 - a03 * a10;
+    let b03 = a01 * a12 - a02 * a11;
+    let b04 = a01 * a13 - a03 * a11;
+    let b05 = a02 * a13 - a03 * a12;
+    let b06 = a20 * a31 - a21 * a30;
+    let b07 = a20 * a32 - a22 * a30;
+    let b08 = a20 * a33 - a23 * a30;
+    let b09 = a21 * a32 - a22 * a31;
+    let b10 = a21 * a33 - a23 * a31;
+    let b11 = a22 * a33 - a23 * a32;
+
+    let inv_det = 1.0
+        / (b00 * b11 - b01 * b10 + b02 * b09 + b03 * b08 - b04 * b07
+            + b05 * b06);
+
+    Matrix::from([
+        (a11 * b11 - a12 * b10 + a13 * b09) * inv_det,
+        (-a01 * b11 + a02 * b10 - a03 * b09) * inv_det,
+        (a31 * b05 - a32 * b04 + a33 * b03) * inv_det,
+        (-a21 * b05 + a22 * b04 - a23 * b03) * inv_det,
+        (-a10 * b11 + a12 * b08 - a13 * b07) * inv_det,
+        (a00 * b11 - a02 * b08 + a03 * b07) * inv_det,
+        (-a30 * b05 + a32 * b02 - a33 * b01) * inv_det,
+        (a20 * b05 - a22 * b02 + a23 * b01) * inv_det,
+        (a10 * b10 - a11 * b08 + a13 * b06) * inv_det,
+        (-a00 * b10 + a01 * b08 - a03 * b06) * inv_det,
+        (a30 * b04 - a31 * b02 + a33 * b00) * inv_det,
+        (-a20 * b04 + a21 * b02 - a23 * b00) * inv_det,
+        (-a10 * b09 + a11 * b07 - a12 * b06) * inv_det,
+        (a00 * b09 - a01 * b07 + a02 * b06) * inv_det,
+        (-a30 * b03 + a31 * b01 - a32 * b00) * inv_det,
+        (a20 * b03 - a21 * b01 + a22 * b00) * inv_det,
+    ])
+}
+
+pub fn MatrixAdd(left: Matrix, right: Matrix) -> Matrix {
+    let l = <[f32; 16]>::from(left);
+    let r = <[f32; 16]>::from(right);
+    Matrix::from(std::array::from_fn(|i| l[i] + r[i]))
+}
+
+pub fn MatrixSubtract(left: Matrix, right: Matrix) -> Matrix {
+    let l = <[f32; 16]>::from(left);
+    let r = <[f32; 16]>::from(right);
+    Matrix::from(std::array::from_fn(|i| l[i] - r[i]))
+}
+
+/// `left` applied first, then `right`, as in raymath
+pub fn MatrixMultiply(left: Matrix, right: Matrix) -> Matrix {
+    let l = <[f32; 16]>::from(left);
+    let r = <[f32; 16]>::from(right);
+    Matrix::from(std::array::from_fn(|n| {
+        let (i, j) = (n / 4, n % 4);
+        (0..4).map(|k| l[4 * i + k] * r[4 * k + j]).sum()
+    }))
+}
+
+pub fn MatrixTranslate(x: f32, y: f32, z: f32) -> Matrix {
+    Matrix {
+        m12: x,
+        m13: y,
+        m14: z,
+        ..MatrixIdentity()
+    }
+}
+
 pub fn MatrixRotate(axis: Vector3, angle: c_float) -> Matrix {
     let mut result = MatrixZero();
     let Vector3 { mut x, mut y, mut z } = axis;
@@ -49,3 +383,355 @@ pub fn MatrixRotate(axis: Vector3, angle: c_float) -> Matrix {
 
     result
 }
+
+pub fn MatrixRotateX(angle: f32) -> Matrix {
+    let (sinres, cosres) = angle.sin_cos();
+    Matrix {
+        m5: cosres,
+        m6: sinres,
+        m9: -sinres,
+        m10: cosres,
+        ..MatrixIdentity()
+    }
+}
+
+pub fn MatrixRotateY(angle: f32) -> Matrix {
+    let (sinres, cosres) = angle.sin_cos();
+    Matrix {
+        m0: cosres,
+        m2: -sinres,
+        m8: sinres,
+        m10: cosres,
+        ..MatrixIdentity()
+    }
+}
+
+pub fn MatrixRotateZ(angle: f32) -> Matrix {
+    let (sinres, cosres) = angle.sin_cos();
+    Matrix {
+        m0: cosres,
+        m1: sinres,
+        m4: -sinres,
+        m5: cosres,
+        ..MatrixIdentity()
+    }
+}
+
+pub fn MatrixRotateXYZ(angle: Vector3) -> Matrix {
+    let (sinz, cosz) = (-angle.z).sin_cos();
+    let (siny, cosy) = (-angle.y).sin_cos();
+    let (sinx, cosx) = (-angle.x).sin_cos();
+    Matrix {
+        m0: cosz * cosy,
+        m1: (cosz * siny * sinx) - (sinz * cosx),
+        m2: (cosz * siny * cosx) + (sinz * sinx),
+        m4: sinz * cosy,
+        m5: (sinz * siny * sinx) + (cosz * cosx),
+        m6: (sinz * siny * cosx) - (cosz * sinx),
+        m8: -siny,
+        m9: cosy * sinx,
+        m10: cosy * cosx,
+        ..MatrixIdentity()
+    }
+}
+
+pub fn MatrixRotateZYX(angle: Vector3) -> Matrix {
+    let (sz, cz) = angle.z.sin_cos();
+    let (sy, cy) = angle.y.sin_cos();
+    let (sx, cx) = angle.x.sin_cos();
+    Matrix {
+        m0: cz * cy,
+        m4: cz * sy * sx - cx * sz,
+        m8: sz * sx + cz * cx * sy,
+        m1: cy * sz,
+        m5: cz * cx + sz * sy * sx,
+        m9: cx * sz * sy - cz * sx,
+        m2: -sy,
+        m6: cy * sx,
+        m10: cy * cx,
+        ..MatrixIdentity()
+    }
+}
+
+pub fn MatrixScale(x: f32, y: f32, z: f32) -> Matrix {
+    Matrix {
+        m0: x,
+        m5: y,
+        m10: z,
+        ..MatrixIdentity()
+    }
+}
+
+pub fn MatrixFrustum(
+    left: f64,
+    right: f64,
+    bottom: f64,
+    top: f64,
+    near: f64,
+    far: f64,
+) -> Matrix {
+    let rl = (right - left) as f32;
+    let tb = (top - bottom) as f32;
+    let fn_ = (far - near) as f32;
+    Matrix {
+        m0: (near as f32 * 2.0) / rl,
+        m5: (near as f32 * 2.0) / tb,
+        m8: (right as f32 + left as f32) / rl,
+        m9: (top as f32 + bottom as f32) / tb,
+        m10: -(far as f32 + near as f32) / fn_,
+        m11: -1.0,
+        m14: -(far as f32 * near as f32 * 2.0) / fn_,
+        ..MatrixZero()
+    }
+}
+
+pub fn MatrixPerspective(
+    fovy: f64,
+    aspect: f64,
+    near: f64,
+    far: f64,
+) -> Matrix {
+    let top = near * (fovy * 0.5).tan();
+    let right = top * aspect;
+    MatrixFrustum(-right, right, -top, top, near, far)
+}
+
+pub fn MatrixOrtho(
+    left: f64,
+    right: f64,
+    bottom: f64,
+    top: f64,
+    near: f64,
+    far: f64,
+) -> Matrix {
+    let rl = (right - left) as f32;
+    let tb = (top - bottom) as f32;
+    let fn_ = (far - near) as f32;
+    Matrix {
+        m0: 2.0 / rl,
+        m5: 2.0 / tb,
+        m10: -2.0 / fn_,
+        m12: -(left as f32 + right as f32) / rl,
+        m13: -(top as f32 + bottom as f32) / tb,
+        m14: -(far as f32 + near as f32) / fn_,
+        m15: 1.0,
+        ..MatrixZero()
+    }
+}
+
+pub fn MatrixLookAt(eye: Vector3, target: Vector3, up: Vector3) -> Matrix {
+    let vz = (eye - target).normalize();
+    let vx = up.cross(vz).normalize();
+    let vy = vz.cross(vx);
+    #[rustfmt::skip]
+    let result = Matrix {
+        m0: vx.x, m4: vx.y, m8: vx.z, m12: -vx.dot(eye),
+        m1: vy.x, m5: vy.y, m9: vy.z, m13: -vy.dot(eye),
+        m2: vz.x, m6: vz.y, m10: vz.z, m14: -vz.dot(eye),
+        m3: 0.0, m7: 0.0, m11: 0.0, m15: 1.0,
+    };
+    result
+}
+
+pub fn MatrixToFloatV(mat: Matrix) -> [f32; 16] {
+    mat.into()
+}
+
+/// Split `mat`, built as scale * rotation * translation, back into
+/// translation, rotation and scale, discarding any shear. raymath returns these
+/// through pointers
+pub fn MatrixDecompose(mat: Matrix) -> (Vector3, Quaternion, Vector3) {
+    let translation = Vector3::new(mat.m12, mat.m13, mat.m14);
+
+    // the basis vectors, each scaled and rotated
+    let mut columns = [
+        Vector3::new(mat.m0, mat.m1, mat.m2),
+        Vector3::new(mat.m4, mat.m5, mat.m6),
+        Vector3::new(mat.m8, mat.m9, mat.m10),
+    ];
+
+    // max-normalizing helps numerical stability
+    let eps = EPSILON as f32;
+    let stabilizer = columns
+        .iter()
+        .flat_map(|c| [c.x.abs(), c.y.abs(), c.z.abs()])
+        .fold(eps, f32::max);
+    for column in &mut columns {
+        *column = column.scale(1.0 / stabilizer);
+    }
+
+    let mut scale = Vector3::zero();
+    scale.x = columns[0].length();
+    if scale.x > eps {
+        columns[0] = columns[0].scale(1.0 / scale.x);
+    }
+
+    // make the second column orthogonal to the first, dropping the shear
+    let shear = columns[0].dot(columns[1]);
+    columns[1] -= columns[0].scale(shear);
+    scale.y = columns[1].length();
+    if scale.y > eps {
+        columns[1] = columns[1].scale(1.0 / scale.y);
+    }
+
+    let shear = columns[0].dot(columns[2]);
+    columns[2] -= columns[0].scale(shear);
+    let shear = columns[1].dot(columns[2]);
+    columns[2] -= columns[1].scale(shear);
+    scale.z = columns[2].length();
+    if scale.z > eps {
+        columns[2] = columns[2].scale(1.0 / scale.z);
+    }
+
+    // the columns are orthonormal; flip them if needed to make a rotation
+    if columns[0].dot(columns[1].cross(columns[2])) < 0.0 {
+        scale = -scale;
+        for column in &mut columns {
+            *column = -*column;
+        }
+    }
+
+    let [c0, c1, c2] = columns;
+    #[rustfmt::skip]
+    let rotation = Matrix {
+        m0: c0.x, m4: c1.x, m8: c2.x, m12: 0.0,
+        m1: c0.y, m5: c1.y, m9: c2.y, m13: 0.0,
+        m2: c0.z, m6: c1.z, m10: c2.z, m14: 0.0,
+        m3: 0.0, m7: 0.0, m11: 0.0, m15: 1.0,
+    };
+    (
+        translation,
+        QuaternionFromMatrix(rotation),
+        scale.scale(stabilizer),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::FRAC_PI_2;
+
+    use super::*;
+
+    #[track_caller]
+    fn assert_close(got: Matrix, want: Matrix) {
+        let g = <[f32; 16]>::from(got);
+        let w = <[f32; 16]>::from(want);
+        assert!(
+            g.iter().zip(w).all(|(g, w)| (g - w).abs() < 1e-4),
+            "{got:?} != {want:?}"
+        );
+    }
+
+    #[track_caller]
+    fn assert_close_v(got: Vector3, want: Vector3) {
+        assert!((got - want).length() < 1e-4, "{got:?} != {want:?}");
+    }
+
+    fn sample() -> Matrix {
+        Matrix::scale(2.0, 3.0, 0.5)
+            * Matrix::rotate(Vector3::new(1.0, 2.0, -1.0), 0.7)
+            * Matrix::translate(4.0, -5.0, 6.0)
+    }
+
+    #[test]
+    fn array_round_trip() {
+        let elements: [f32; 16] = std::array::from_fn(|i| i as f32);
+        let mat = Matrix::from(elements);
+        assert_eq!((mat.m0, mat.m1, mat.m4, mat.m12), (0.0, 1.0, 4.0, 12.0));
+        assert_eq!(<[f32; 16]>::from(mat), elements);
+        assert_eq!(MatrixToFloatV(mat), elements);
+    }
+
+    #[test]
+    fn translate_and_scale() {
+        let p = Vector3::new(1.0, 2.0, 3.0);
+        let m = Matrix::scale(2.0, 2.0, 2.0) * Matrix::translate(1.0, 0.0, 0.0);
+        assert_eq!(p.transform(m), Vector3::new(3.0, 4.0, 6.0));
+        assert_eq!(Matrix::scale(2.0, 3.0, 4.0).determinant(), 24.0);
+        assert_eq!(Matrix::scale(2.0, 3.0, 4.0).trace(), 10.0);
+        assert_eq!(Matrix::default(), Matrix::identity());
+    }
+
+    #[test]
+    fn rotations() {
+        let x = Vector3::new(1.0, 0.0, 0.0);
+        let y = Vector3::new(0.0, 1.0, 0.0);
+        let z = Vector3::new(0.0, 0.0, 1.0);
+        assert_close_v(y.transform(Matrix::rotate_x(FRAC_PI_2)), z);
+        assert_close_v(z.transform(Matrix::rotate_y(FRAC_PI_2)), x);
+        assert_close_v(x.transform(Matrix::rotate_z(FRAC_PI_2)), y);
+        assert_close(Matrix::rotate_x(0.3), Matrix::rotate(x, 0.3));
+
+        let angles = Vector3::new(0.3, -1.2, 2.0);
+        let (rx, ry, rz) = (
+            Matrix::rotate_x(angles.x),
+            Matrix::rotate_y(angles.y),
+            Matrix::rotate_z(angles.z),
+        );
+        assert_close(Matrix::rotate_xyz(angles), rz * ry * rx);
+        assert_close(Matrix::rotate_zyx(angles), rx * ry * rz);
+    }
+
+    #[test]
+    fn invert_and_transpose() {
+        let m = sample();
+        assert_close(m * m.invert(), Matrix::identity());
+        assert_close(m.invert() * m, Matrix::identity());
+        assert!(
+            (m.determinant() * m.invert().determinant() - 1.0).abs() < 1e-4
+        );
+        assert_close(m.transpose().transpose(), m);
+        assert_eq!(m.transpose().m1, m.m4);
+        assert_close(m + m - m, m);
+    }
+
+    #[test]
+    fn projections() {
+        let ortho = Matrix::ortho(-2.0, 2.0, -1.0, 1.0, 0.1, 10.0);
+        let corner = Vector3::new(2.0, 1.0, -0.1).transform(ortho);
+        assert_close_v(corner, Vector3::new(1.0, 1.0, -1.0));
+
+        let perspective = Matrix::perspective(FRAC_PI_2 as f64, 2.0, 1.0, 10.0);
+        let frustum = Matrix::frustum(-2.0, 2.0, -1.0, 1.0, 1.0, 10.0);
+        assert_close(perspective, frustum);
+        assert_eq!(perspective.m11, -1.0);
+    }
+
+    #[test]
+    fn look_at() {
+        let eye = Vector3::new(0.0, 0.0, 5.0);
+        let view =
+            Matrix::look_at(eye, Vector3::zero(), Vector3::new(0.0, 1.0, 0.0));
+        assert_close_v(eye.transform(view), Vector3::zero());
+        assert_close_v(
+            Vector3::zero().transform(view),
+            Vector3::new(0.0, 0.0, -5.0),
+        );
+    }
+
+    #[test]
+    fn decompose() {
+        let axis = Vector3::new(1.0, 2.0, -1.0);
+        let (translation, rotation, scale) = sample().decompose();
+        assert_close_v(translation, Vector3::new(4.0, -5.0, 6.0));
+        assert_close_v(scale, Vector3::new(2.0, 3.0, 0.5));
+        let v = Vector3::new(0.3, -0.4, 2.0);
+        assert_close_v(
+            v.rotate_by_quaternion(rotation),
+            v.transform(Matrix::rotate(axis, 0.7)),
+        );
+    }
+
+    #[test]
+    fn debug_grid() {
+        let got = format!("{:.1?}", Matrix::translate(1.0, 2.0, 3.0));
+        let want = "\
+Matrix [
+    [    1.0,     0.0,     0.0,     1.0],
+    [    0.0,     1.0,     0.0,     2.0],
+    [    0.0,     0.0,     1.0,     3.0],
+    [    0.0,     0.0,     0.0,     1.0],
+]";
+        assert_eq!(got, want);
+    }
+}
@@ -0,0 +1,529 @@
+//! `Quaternion` is raylib's `Vector4`, so the methods and operators here are
+//! available on both. `*` is the quaternion product rather than a
+//! component-wise multiply.
+
+use std::ops::{Mul, MulAssign};
+
+use crate::{matrix::MatrixIdentity, Matrix, Quaternion, Vector3, EPSILON};
+
+impl Quaternion {
+    pub fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
+        Self { x, y, z, w }
+    }
+
+    pub fn identity() -> Self {
+        QuaternionIdentity()
+    }
+
+    /// rotation of `angle` radians around `axis`
+    pub fn from_axis_angle(axis: Vector3, angle: f32) -> Self {
+        QuaternionFromAxisAngle(axis, angle)
+    }
+
+    /// the normalized axis and the angle in radians, the inverse of
+    /// [Quaternion::from_axis_angle]
+    pub fn to_axis_angle(self) -> (Vector3, f32) {
+        QuaternionToAxisAngle(self)
+    }
+
+    /// rotation from euler angles in radians, `pitch` around x, `yaw` around y
+    /// and `roll` around z, applied in that order like [Matrix::rotate_zyx]
+    pub fn from_euler(pitch: f32, yaw: f32, roll: f32) -> Self {
+        QuaternionFromEuler(pitch, yaw, roll)
+    }
+
+    /// euler angles in radians as `(pitch, yaw, roll)` in x, y and z, the
+    /// inverse of [Quaternion::from_euler]
+    pub fn to_euler(self) -> Vector3 {
+        QuaternionToEuler(self)
+    }
+
+    /// rotation part of `mat`
+    pub fn from_matrix(mat: Matrix) -> Self {
+        QuaternionFromMatrix(mat)
+    }
+
+    pub fn to_matrix(self) -> Matrix {
+        QuaternionToMatrix(self)
+    }
+
+    /// shortest rotation turning the unit vector `from` into the unit vector
+    /// `to`, or a half turn about some axis perpendicular to `from` if they
+    /// are opposite
+    pub fn from_vector3_to_vector3(from: Vector3, to: Vector3) -> Self {
+        QuaternionFromVector3ToVector3(from, to)
+    }
+
+    pub fn length(self) -> f32 {
+        QuaternionLength(self)
+    }
+
+    pub fn normalize(self) -> Self {
+        QuaternionNormalize(self)
+    }
+
+    /// the opposite rotation for unit quaternions
+    pub fn invert(self) -> Self {
+        QuaternionInvert(self)
+    }
+
+    /// rotation applying `other` first, then `self`; same as `self * other`
+    pub fn multiply(self, other: Self) -> Self {
+        QuaternionMultiply(self, other)
+    }
+
+    pub fn rotate(self, v: Vector3) -> Vector3 {
+        v.rotate_by_quaternion(self)
+    }
+
+    pub fn lerp(self, other: Self, amount: f32) -> Self {
+        QuaternionLerp(self, other, amount)
+    }
+
+    /// normalized [Quaternion::lerp], cheaper than [Quaternion::slerp] and
+    /// close to it for nearby rotations
+    pub fn nlerp(self, other: Self, amount: f32) -> Self {
+        QuaternionNlerp(self, other, amount)
+    }
+
+    /// spherical interpolation at a constant angular speed, along the shorter
+    /// path
+    pub fn slerp(self, other: Self, amount: f32) -> Self {
+        QuaternionSlerp(self, other, amount)
+    }
+
+    pub fn transform(self, mat: Matrix) -> Self {
+        QuaternionTransform(self, mat)
+    }
+
+    /// approximately equal, relative to the size of the components
+    pub fn equals(self, other: Self) -> bool {
+        QuaternionEquals(self, other)
+    }
+}
+
+impl Mul for Quaternion {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        QuaternionMultiply(self, rhs)
+    }
+}
+
+impl MulAssign for Quaternion {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = QuaternionMultiply(*self, rhs);
+    }
+}
+
+/// rotate a vector
+impl Mul<Vector3> for Quaternion {
+    type Output = Vector3;
+
+    fn mul(self, rhs: Vector3) -> Vector3 {
+        rhs.rotate_by_quaternion(self)
+    }
+}
+
+impl PartialEq for Quaternion {
+    fn eq(&self, other: &Self) -> bool {
+        self.x == other.x
+            && self.y == other.y
+            && self.z == other.z
+            && self.w == other.w
+    }
+}
+
+pub fn QuaternionIdentity() -> Quaternion {
+    Quaternion { x: 0.0, y: 0.0, z: 0.0, w: 1.0 }
+}
+
+pub fn QuaternionLength(q: Quaternion) -> f32 {
+    (q.x * q.x + q.y * q.y + q.z * q.z + q.w * q.w).sqrt()
+}
+
+pub fn QuaternionNormalize(q: Quaternion) -> Quaternion {
+    let mut length = QuaternionLength(q);
+    if length == 0.0 {
+        length = 1.0;
+    }
+    let ilength = 1.0 / length;
+    Quaternion {
+        x: q.x * ilength,
+        y: q.y * ilength,
+        z: q.z * ilength,
+        w: q.w * ilength,
+    }
+}
+
+pub fn QuaternionInvert(q: Quaternion) -> Quaternion {
+    let length_sq = q.x * q.x + q.y * q.y + q.z * q.z + q.w * q.w;
+    if length_sq == 0.0 {
+        return q;
+    }
+    let inv = 1.0 / length_sq;
+    Quaternion {
+        x: -q.x * inv,
+        y: -q.y * inv,
+        z: -q.z * inv,
+        w: q.w * inv,
+    }
+}
+
+pub fn QuaternionMultiply(q1: Quaternion, q2: Quaternion) -> Quaternion {
+    let Quaternion { x: qax, y: qay, z: qaz, w: qaw } = q1;
+    let Quaternion { x: qbx, y: qby, z: qbz, w: qbw } = q2;
+    Quaternion {
+        x: qax * qbw + qaw * qbx + qay * qbz - qaz * qby,
+        y: qay * qbw + qaw * qby + qaz * qbx - qax * qbz,
+        z: qaz * qbw + qaw * qbz + qax * qby - qay * qbx,
+        w: qaw * qbw - qax * qbx - qay * qby - qaz * qbz,
+    }
+}
+
+pub fn QuaternionScale(q: Quaternion, mul: f32) -> Quaternion {
+    Quaternion {
+        x: q.x * mul,
+        y: q.y * mul,
+        z: q.z * mul,
+        w: q.w * mul,
+    }
+}
+
+pub fn QuaternionLerp(
+    q1: Quaternion,
+    q2: Quaternion,
+    amount: f32,
+) -> Quaternion {
+    Quaternion {
+        x: q1.x + amount * (q2.x - q1.x),
+        y: q1.y + amount * (q2.y - q1.y),
+        z: q1.z + amount * (q2.z - q1.z),
+        w: q1.w + amount * (q2.w - q1.w),
+    }
+}
+
+pub fn QuaternionNlerp(
+    q1: Quaternion,
+    q2: Quaternion,
+    amount: f32,
+) -> Quaternion {
+    QuaternionNormalize(QuaternionLerp(q1, q2, amount))
+}
+
+pub fn QuaternionSlerp(
+    q1: Quaternion,
+    mut q2: Quaternion,
+    amount: f32,
+) -> Quaternion {
+    let mut cos_half_theta =
+        q1.x * q2.x + q1.y * q2.y + q1.z * q2.z + q1.w * q2.w;
+    if cos_half_theta < 0.0 {
+        q2 = QuaternionScale(q2, -1.0);
+        cos_half_theta = -cos_half_theta;
+    }
+
+    if cos_half_theta.abs() >= 1.0 {
+        q1
+    } else if cos_half_theta > 0.95 {
+        QuaternionNlerp(q1, q2, amount)
+    } else {
+        let half_theta = cos_half_theta.acos();
+        let sin_half_theta = (1.0 - cos_half_theta * cos_half_theta).sqrt();
+        if sin_half_theta.abs() < EPSILON as f32 {
+            QuaternionLerp(q1, q2, 0.5)
+        } else {
+            let ratio_a = ((1.0 - amount) * half_theta).sin() / sin_half_theta;
+            let ratio_b = (amount * half_theta).sin() / sin_half_theta;
+            Quaternion {
+                x: q1.x * ratio_a + q2.x * ratio_b,
+                y: q1.y * ratio_a + q2.y * ratio_b,
+                z: q1.z * ratio_a + q2.z * ratio_b,
+                w: q1.w * ratio_a + q2.w * ratio_b,
+            }
+        }
+    }
+}
+
+pub fn QuaternionFromVector3ToVector3(
+    from: Vector3,
+    to: Vector3,
+) -> Quaternion {
+    let cos2_theta = from.dot(to);
+    let cross = from.cross(to);
+    let q = Quaternion {
+        x: cross.x,
+        y: cross.y,
+        z: cross.z,
+        w: 1.0 + cos2_theta,
+    };
+    if QuaternionLength(q) < EPSILON as f32 {
+        // opposite vectors have no shortest rotation, and raymath returns a
+        // zero quaternion. any half turn about a perpendicular axis works
+        let axis = from.perpendicular().normalize();
+        return Quaternion {
+            x: axis.x,
+            y: axis.y,
+            z: axis.z,
+            w: 0.0,
+        };
+    }
+    QuaternionNormalize(q)
+}
+
+pub fn QuaternionTransform(q: Quaternion, mat: Matrix) -> Quaternion {
+    Quaternion {
+        x: mat.m0 * q.x + mat.m4 * q.y + mat.m8 * q.z + mat.m12 * q.w,
+        y: mat.m1 * q.x + mat.m5 * q.y + mat.m9 * q.z + mat.m13 * q.w,
+        z: mat.m2 * q.x + mat.m6 * q.y + mat.m10 * q.z + mat.m14 * q.w,
+        w: mat.m3 * q.x + mat.m7 * q.y + mat.m11 * q.z + mat.m15 * q.w,
+    }
+}
+
+pub fn QuaternionFromMatrix(mat: Matrix) -> Quaternion {
+    let four_w_squared_minus1 = mat.m0 + mat.m5 + mat.m10;
+    let four_x_squared_minus1 = mat.m0 - mat.m5 - mat.m10;
+    let four_y_squared_minus1 = mat.m5 - mat.m0 - mat.m10;
+    let four_z_squared_minus1 = mat.m10 - mat.m0 - mat.m5;
+
+    let mut biggest_index = 0;
+    let mut four_biggest_squared_minus1 = four_w_squared_minus1;
+    if four_x_squared_minus1 > four_biggest_squared_minus1 {
+        four_biggest_squared_minus1 = four_x_squared_minus1;
+        biggest_index = 1;
+    }
+    if four_y_squared_minus1 > four_biggest_squared_minus1 {
+        four_biggest_squared_minus1 = four_y_squared_minus1;
+        biggest_index = 2;
+    }
+    if four_z_squared_minus1 > four_biggest_squared_minus1 {
+        four_biggest_squared_minus1 = four_z_squared_minus1;
+        biggest_index = 3;
+    }
+
+    let biggest_val = (four_biggest_squared_minus1 + 1.0).sqrt() * 0.5;
+    let mult = 0.25 / biggest_val;
+
+    match biggest_index {
+        0 => Quaternion {
+            w: biggest_val,
+            x: (mat.m6 - mat.m9) * mult,
+            y: (mat.m8 - mat.m2) * mult,
+            z: (mat.m1 - mat.m4) * mult,
+        },
+        1 => Quaternion {
+            x: biggest_val,
+            w: (mat.m6 - mat.m9) * mult,
+            y: (mat.m1 + mat.m4) * mult,
+            z: (mat.m8 + mat.m2) * mult,
+        },
+        2 => Quaternion {
+            y: biggest_val,
+            w: (mat.m8 - mat.m2) * mult,
+            x: (mat.m1 + mat.m4) * mult,
+            z: (mat.m6 + mat.m9) * mult,
+        },
+        _ => Quaternion {
+            z: biggest_val,
+            w: (mat.m1 - mat.m4) * mult,
+            x: (mat.m8 + mat.m2) * mult,
+            y: (mat.m6 + mat.m9) * mult,
+        },
+    }
+}
+
+pub fn QuaternionToMatrix(q: Quaternion) -> Matrix {
+    let a2 = q.x * q.x;
+    let b2 = q.y * q.y;
+    let c2 = q.z * q.z;
+    let ac = q.x * q.z;
+    let ab = q.x * q.y;
+    let bc = q.y * q.z;
+    let ad = q.w * q.x;
+    let bd = q.w * q.y;
+    let cd = q.w * q.z;
+    Matrix {
+        m0: 1.0 - 2.0 * (b2 + c2),
+        m1: 2.0 * (ab + cd),
+        m2: 2.0 * (ac - bd),
+        m4: 2.0 * (ab - cd),
+        m5: 1.0 - 2.0 * (a2 + c2),
+        m6: 2.0 * (bc + ad),
+        m8: 2.0 * (ac + bd),
+        m9: 2.0 * (bc - ad),
+        m10: 1.0 - 2.0 * (a2 + b2),
+        ..MatrixIdentity()
+    }
+}
+
+pub fn QuaternionFromAxisAngle(axis: Vector3, angle: f32) -> Quaternion {
+    let axis_length = axis.length();
+    if axis_length == 0.0 {
+        return QuaternionIdentity();
+    }
+    let axis = axis.normalize();
+    let half = angle * 0.5;
+    let sinres = half.sin();
+    let cosres = half.cos();
+    QuaternionNormalize(Quaternion {
+        x: axis.x * sinres,
+        y: axis.y * sinres,
+        z: axis.z * sinres,
+        w: cosres,
+    })
+}
+
+pub fn QuaternionToAxisAngle(mut q: Quaternion) -> (Vector3, f32) {
+    if q.w.abs() > 1.0 {
+        q = QuaternionNormalize(q);
+    }
+    let angle = 2.0 * q.w.acos();
+    let den = (1.0 - q.w * q.w).sqrt();
+    let axis = if den > EPSILON as f32 {
+        Vector3::new(q.x / den, q.y / den, q.z / den)
+    } else {
+        // no rotation, so any axis will do
+        Vector3::new(1.0, 0.0, 0.0)
+    };
+    (axis, angle)
+}
+
+pub fn QuaternionFromEuler(pitch: f32, yaw: f32, roll: f32) -> Quaternion {
+    let (x1, x0) = (pitch * 0.5).sin_cos();
+    let (y1, y0) = (yaw * 0.5).sin_cos();
+    let (z1, z0) = (roll * 0.5).sin_cos();
+    Quaternion {
+        x: x1 * y0 * z0 - x0 * y1 * z1,
+        y: x0 * y1 * z0 + x1 * y0 * z1,
+        z: x0 * y0 * z1 - x1 * y1 * z0,
+        w: x0 * y0 * z0 + x1 * y1 * z1,
+    }
+}
+
+pub fn QuaternionToEuler(q: Quaternion) -> Vector3 {
+    let x0 = 2.0 * (q.w * q.x + q.y * q.z);
+    let x1 = 1.0 - 2.0 * (q.x * q.x + q.y * q.y);
+    let y0 = (2.0 * (q.w * q.y - q.z * q.x)).clamp(-1.0, 1.0);
+    let z0 = 2.0 * (q.w * q.z + q.x * q.y);
+    let z1 = 1.0 - 2.0 * (q.y * q.y + q.z * q.z);
+    Vector3::new(x0.atan2(x1), y0.asin(), z0.atan2(z1))
+}
+
+pub fn QuaternionEquals(p: Quaternion, q: Quaternion) -> bool {
+    let eps = EPSILON as f32;
+    let close = |a: f32, b: f32| {
+        (a - b).abs() <= eps * 1.0f32.max(a.abs().max(b.abs()))
+    };
+    (close(p.x, q.x) && close(p.y, q.y) && close(p.z, q.z) && close(p.w, q.w))
+        || (close(p.x, -q.x)
+            && close(p.y, -q.y)
+            && close(p.z, -q.z)
+            && close(p.w, -q.w))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::{FRAC_PI_2, PI};
+
+    use super::*;
+    use crate::matrix::MatrixRotateZYX;
+
+    fn close(a: Vector3, b: Vector3) -> bool {
+        (a - b).length() < 1e-5
+    }
+
+    fn same_rotation(p: Quaternion, q: Quaternion) -> bool {
+        let dot = p.x * q.x + p.y * q.y + p.z * q.z + p.w * q.w;
+        dot.abs() > 1.0 - 1e-5
+    }
+
+    #[test]
+    fn rotate_vector() {
+        let q =
+            Quaternion::from_axis_angle(Vector3::new(0.0, 1.0, 0.0), FRAC_PI_2);
+        let got = Vector3::new(1.0, 0.0, 0.0).rotate_by_quaternion(q);
+        assert!(close(got, Vector3::new(0.0, 0.0, -1.0)));
+    }
+
+    #[test]
+    fn multiply_composes_rotations() {
+        let x = Quaternion::from_axis_angle(Vector3::new(1.0, 0.0, 0.0), 0.3);
+        let y = Quaternion::from_axis_angle(Vector3::new(0.0, 1.0, 0.0), 1.1);
+        let v = Vector3::new(0.5, -2.0, 3.0);
+        let want = v.rotate_by_quaternion(x).rotate_by_quaternion(y);
+        let got = v.rotate_by_quaternion(QuaternionMultiply(y, x));
+        assert!(close(got, want));
+        assert!((QuaternionMultiply(y, x).length() - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn euler_round_trip() {
+        let angles = Vector3::new(0.4, -0.7, 1.2);
+        let q = Quaternion::from_euler(angles.x, angles.y, angles.z);
+        assert!(close(q.to_euler(), angles));
+        let v = Vector3::new(1.0, 2.0, 3.0);
+        assert!(close(q * v, v.transform(MatrixRotateZYX(angles))));
+    }
+
+    #[test]
+    fn matrix_round_trip() {
+        let axis = Vector3::new(1.0, -2.0, 0.5);
+        let q = Quaternion::from_axis_angle(axis, 2.5);
+        let mat = q.to_matrix();
+        let want: [f32; 16] = Matrix::rotate(axis, 2.5).into();
+        let got: [f32; 16] = mat.into();
+        assert!(got.iter().zip(want).all(|(a, b)| (a - b).abs() < 1e-5));
+        assert!(same_rotation(Quaternion::from_matrix(mat), q));
+
+        let (got_axis, angle) = q.to_axis_angle();
+        assert!(close(got_axis, axis.normalize()));
+        assert!((angle - 2.5).abs() < 1e-5);
+    }
+
+    #[test]
+    fn invert() {
+        let q = Quaternion::from_axis_angle(Vector3::new(0.0, 1.0, 1.0), 0.8);
+        assert!((q * q.invert()).equals(Quaternion::identity()));
+        let v = Vector3::new(3.0, -1.0, 2.0);
+        assert!(close(q.invert().rotate(q.rotate(v)), v));
+    }
+
+    #[test]
+    fn from_vector3_to_vector3() {
+        let from = Vector3::new(1.0, 0.0, 0.0);
+        let to = Vector3::new(0.0, 0.6, 0.8);
+        let q = Quaternion::from_vector3_to_vector3(from, to);
+        assert!(close(q * from, to));
+
+        for from in [
+            Vector3::new(1.0, 0.0, 0.0),
+            Vector3::new(0.0, 0.0, -1.0),
+            Vector3::new(0.0, 0.6, 0.8),
+        ] {
+            let q = Quaternion::from_vector3_to_vector3(from, -from);
+            assert!((q.length() - 1.0).abs() < 1e-6);
+            assert!(close(q * from, -from));
+        }
+    }
+
+    #[test]
+    fn interpolation() {
+        let axis = Vector3::new(0.0, 0.0, 1.0);
+        let a = Quaternion::identity();
+        let b = Quaternion::from_axis_angle(axis, FRAC_PI_2);
+        let half = Quaternion::from_axis_angle(axis, PI / 4.0);
+        assert!(same_rotation(a.slerp(b, 0.5), half));
+        assert!(same_rotation(a.nlerp(b, 0.5), half));
+        assert!(same_rotation(a.slerp(b, 0.0), a));
+        assert!(same_rotation(a.slerp(b, 1.0), b));
+
+        // b and -b are the same rotation, so the path stays short
+        let flipped = QuaternionScale(b, -1.0);
+        assert!(same_rotation(a.slerp(flipped, 0.5), half));
+
+        // constant angular speed, unlike nlerp over a wide angle
+        let c = Quaternion::from_axis_angle(axis, 2.5);
+        let (_, angle) = a.slerp(c, 0.25).to_axis_angle();
+        assert!((angle - 2.5 * 0.25).abs() < 1e-5);
+    }
+}
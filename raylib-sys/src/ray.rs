@@ -0,0 +1,355 @@
+//! Ray casting against 3D shapes, ported from raylib's `rmodels.c` so it
+//! works without an open window. The ray direction should be normalized for
+//! [RayCollision::distance] to be in world units.
+
+use std::slice;
+
+use crate::{BoundingBox, Matrix, Mesh, Ray, RayCollision, Vector3, EPSILON};
+
+impl Ray {
+    pub fn new(position: Vector3, direction: Vector3) -> Self {
+        Self { position, direction }
+    }
+
+    /// the point `distance` along the ray
+    pub fn at(&self, distance: f32) -> Vector3 {
+        self.position + self.direction.scale(distance)
+    }
+
+    /// like `GetRayCollisionSphere`, except spheres behind the ray are missed.
+    /// From inside the sphere the hit is on the far side, with the normal
+    /// pointing inwards
+    pub fn collide_sphere(&self, center: Vector3, radius: f32) -> RayCollision {
+        let to_center = center - self.position;
+        let along = to_center.dot(self.direction);
+        let distance_sqr = to_center.length_sqr();
+        let d = radius * radius - (distance_sqr - along * along);
+        if d < 0.0 {
+            return RayCollision::default();
+        }
+
+        let inside = distance_sqr < radius * radius;
+        let distance = if inside {
+            along + d.sqrt()
+        } else {
+            along - d.sqrt()
+        };
+        let point = self.at(distance);
+        let normal = (point - center).normalize();
+        RayCollision {
+            hit: distance >= 0.0,
+            distance,
+            point,
+            normal: if inside { -normal } else { normal },
+        }
+    }
+
+    /// like `GetRayCollisionBox`. From inside the box the hit is where the ray
+    /// leaves it, with the normal pointing inwards
+    pub fn collide_box(&self, bounds: BoundingBox) -> RayCollision {
+        let BoundingBox { min, max } = bounds;
+        let p = self.position;
+        let inside = p.x > min.x
+            && p.x < max.x
+            && p.y > min.y
+            && p.y < max.y
+            && p.z > min.z
+            && p.z < max.z;
+        let direction = if inside {
+            -self.direction
+        } else {
+            self.direction
+        };
+
+        // slab test: the distances to the planes of each pair of faces
+        let inv = Vector3::one() / direction;
+        let t_min = (min - p) * inv;
+        let t_max = (max - p) * inv;
+        let near = t_min.min(t_max);
+        let far = t_min.max(t_max);
+        let enter = near.x.max(near.y).max(near.z);
+        let exit = far.x.min(far.y).min(far.z);
+
+        let point = p + direction.scale(enter);
+        // scaled to a unit cube the hit face is the one component just past
+        // 1, so truncating leaves the face normal
+        let local = (point - bounds.center()).scale(2.01) / (max - min);
+        let normal =
+            Vector3::new(local.x.trunc(), local.y.trunc(), local.z.trunc())
+                .normalize();
+        RayCollision {
+            hit: exit >= 0.0 && enter <= exit,
+            distance: if inside { -enter } else { enter },
+            point,
+            normal: if inside { -normal } else { normal },
+        }
+    }
+
+    /// like `GetRayCollisionTriangle`. The normal follows the winding
+    /// `p1`, `p2`, `p3` by the right hand rule, whichever side is hit
+    pub fn collide_triangle(
+        &self,
+        p1: Vector3,
+        p2: Vector3,
+        p3: Vector3,
+    ) -> RayCollision {
+        let eps = EPSILON as f32;
+        let miss = RayCollision::default();
+
+        // Möller–Trumbore
+        let edge1 = p2 - p1;
+        let edge2 = p3 - p1;
+        let p = self.direction.cross(edge2);
+        let det = edge1.dot(p);
+        if det.abs() < eps {
+            return miss;
+        }
+        let inv_det = 1.0 / det;
+
+        let tv = self.position - p1;
+        let u = tv.dot(p) * inv_det;
+        if !(0.0..=1.0).contains(&u) {
+            return miss;
+        }
+        let q = tv.cross(edge1);
+        let v = self.direction.dot(q) * inv_det;
+        if v < 0.0 || u + v > 1.0 {
+            return miss;
+        }
+
+        let distance = edge2.dot(q) * inv_det;
+        if distance <= eps {
+            return miss;
+        }
+        RayCollision {
+            hit: true,
+            distance,
+            point: self.at(distance),
+            normal: edge1.cross(edge2).normalize(),
+        }
+    }
+
+    /// like `GetRayCollisionQuad`, for the quad with corners `p1` to `p4` in
+    /// order
+    pub fn collide_quad(
+        &self,
+        p1: Vector3,
+        p2: Vector3,
+        p3: Vector3,
+        p4: Vector3,
+    ) -> RayCollision {
+        let collision = self.collide_triangle(p1, p2, p4);
+        if collision.hit {
+            return collision;
+        }
+        self.collide_triangle(p2, p3, p4)
+    }
+
+    /// the nearest hit among the triangles of a mesh placed by `transform`.
+    /// Triangles are every three `indices`, or every three `vertices` if there
+    /// are no indices
+    pub fn collide_triangles(
+        &self,
+        vertices: &[Vector3],
+        indices: Option<&[u16]>,
+        transform: Matrix,
+    ) -> RayCollision {
+        let corner = |i: usize| {
+            let i = indices.map_or(i, |indices| indices[i] as usize);
+            vertices[i].transform(transform)
+        };
+        let count = indices.map_or(vertices.len(), <[u16]>::len) / 3;
+        (0..count)
+            .map(|t| {
+                self.collide_triangle(
+                    corner(t * 3),
+                    corner(t * 3 + 1),
+                    corner(t * 3 + 2),
+                )
+            })
+            .filter(|collision| collision.hit)
+            .min_by(|a, b| a.distance.total_cmp(&b.distance))
+            .unwrap_or_default()
+    }
+
+    /// like `GetRayCollisionMesh`, the nearest hit among the triangles of
+    /// `mesh` placed by `transform`
+    ///
+    /// # Safety
+    ///
+    /// `mesh` must have been loaded by raylib, or its `vertices` and `indices`
+    /// must otherwise be null or point to enough data for `triangleCount`
+    /// triangles
+    pub unsafe fn collide_mesh(
+        &self,
+        mesh: &Mesh,
+        transform: Matrix,
+    ) -> RayCollision {
+        if mesh.vertices.is_null() || mesh.triangleCount <= 0 {
+            return RayCollision::default();
+        }
+        let corners = mesh.triangleCount as usize * 3;
+        let indices = (!mesh.indices.is_null())
+            .then(|| slice::from_raw_parts(mesh.indices, corners));
+        let vertex_count = match indices {
+            Some(_) => mesh.vertexCount as usize,
+            None => corners,
+        };
+        let vertices = slice::from_raw_parts(
+            mesh.vertices as *const Vector3,
+            vertex_count,
+        );
+        self.collide_triangles(vertices, indices, transform)
+    }
+}
+
+/// a miss
+impl Default for RayCollision {
+    fn default() -> Self {
+        Self {
+            hit: false,
+            distance: 0.0,
+            point: Vector3::zero(),
+            normal: Vector3::zero(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn v(x: f32, y: f32, z: f32) -> Vector3 {
+        Vector3::new(x, y, z)
+    }
+
+    fn close(a: Vector3, b: Vector3) -> bool {
+        (a - b).length() < 1e-5
+    }
+
+    /// looking down -z from z = 10
+    fn ray(x: f32, y: f32) -> Ray {
+        Ray::new(v(x, y, 10.0), v(0.0, 0.0, -1.0))
+    }
+
+    #[test]
+    fn sphere() {
+        let hit = ray(0.0, 0.0).collide_sphere(v(0.0, 0.0, 1.0), 2.0);
+        assert!(hit.hit);
+        assert!((hit.distance - 7.0).abs() < 1e-5);
+        assert!(close(hit.point, v(0.0, 0.0, 3.0)));
+        assert!(close(hit.normal, v(0.0, 0.0, 1.0)));
+
+        assert!(!ray(2.1, 0.0).collide_sphere(v(0.0, 0.0, 1.0), 2.0).hit);
+        // behind the ray
+        assert!(!ray(0.0, 0.0).collide_sphere(v(0.0, 0.0, 20.0), 2.0).hit);
+
+        // from inside, out through the far side
+        let inside = ray(0.0, 0.0).collide_sphere(v(0.0, 0.0, 10.0), 2.0);
+        assert!(inside.hit);
+        assert!(close(inside.point, v(0.0, 0.0, 8.0)));
+        assert!(close(inside.normal, v(0.0, 0.0, 1.0)));
+    }
+
+    #[test]
+    fn bounding_box() {
+        let bounds = BoundingBox {
+            min: v(-1.0, -2.0, -3.0),
+            max: v(1.0, 2.0, 3.0),
+        };
+        let hit = ray(0.5, 1.5).collide_box(bounds);
+        assert!(hit.hit);
+        assert!((hit.distance - 7.0).abs() < 1e-5);
+        assert!(close(hit.point, v(0.5, 1.5, 3.0)));
+        assert!(close(hit.normal, v(0.0, 0.0, 1.0)));
+
+        // a side face
+        let side = Ray::new(v(-5.0, 0.0, 0.0), v(1.0, 0.0, 0.0));
+        let hit = side.collide_box(bounds);
+        assert!(hit.hit);
+        assert!(close(hit.normal, v(-1.0, 0.0, 0.0)));
+
+        assert!(!ray(1.5, 0.0).collide_box(bounds).hit);
+        // behind the ray
+        let away = Ray::new(v(0.0, 0.0, 10.0), v(0.0, 0.0, 1.0));
+        assert!(!away.collide_box(bounds).hit);
+
+        // from inside, out through the far side
+        let inside = Ray::new(v(0.0, 0.0, 2.0), v(0.0, 0.0, 1.0));
+        let hit = inside.collide_box(bounds);
+        assert!(hit.hit);
+        assert!((hit.distance - 1.0).abs() < 1e-5);
+        assert!(close(hit.point, v(0.0, 0.0, 3.0)));
+        assert!(close(hit.normal, v(0.0, 0.0, -1.0)));
+    }
+
+    #[test]
+    fn triangle_and_quad() {
+        let (a, b, c) = (v(0.0, 0.0, 0.0), v(2.0, 0.0, 0.0), v(0.0, 2.0, 0.0));
+        let hit = ray(0.5, 0.5).collide_triangle(a, b, c);
+        assert!(hit.hit);
+        assert!((hit.distance - 10.0).abs() < 1e-5);
+        assert!(close(hit.normal, v(0.0, 0.0, 1.0)));
+        // from behind the normal still follows the winding
+        let hit = ray(0.5, 0.5).collide_triangle(a, c, b);
+        assert!(hit.hit);
+        assert!(close(hit.normal, v(0.0, 0.0, -1.0)));
+        assert!(!ray(1.5, 1.5).collide_triangle(a, b, c).hit);
+
+        let d = v(2.0, 2.0, 0.0);
+        assert!(ray(1.5, 1.5).collide_quad(a, b, d, c).hit);
+        assert!(ray(0.2, 0.2).collide_quad(a, b, d, c).hit);
+        assert!(!ray(2.5, 1.5).collide_quad(a, b, d, c).hit);
+    }
+
+    #[test]
+    fn triangles() {
+        // two unit squares facing +z, one at z = 0 and one at z = 2
+        let vertices = [
+            v(0.0, 0.0, 0.0),
+            v(1.0, 0.0, 0.0),
+            v(1.0, 1.0, 0.0),
+            v(0.0, 1.0, 0.0),
+            v(0.0, 0.0, 2.0),
+            v(1.0, 0.0, 2.0),
+            v(1.0, 1.0, 2.0),
+            v(0.0, 1.0, 2.0),
+        ];
+        let indices = [0, 1, 2, 0, 2, 3, 4, 5, 6, 4, 6, 7];
+        let identity = Matrix::identity();
+
+        let hit = ray(0.7, 0.2).collide_triangles(
+            &vertices,
+            Some(&indices),
+            identity,
+        );
+        assert!(hit.hit);
+        assert!(close(hit.point, v(0.7, 0.2, 2.0)));
+
+        let moved = Matrix::translate(0.0, 0.0, -5.0);
+        let hit =
+            ray(0.7, 0.2).collide_triangles(&vertices, Some(&indices), moved);
+        assert!(close(hit.point, v(0.7, 0.2, -3.0)));
+
+        // without indices, only the first square
+        let hit =
+            ray(0.2, 0.7).collide_triangles(&vertices[..3], None, identity);
+        assert!(!hit.hit);
+        let hit =
+            ray(0.7, 0.2).collide_triangles(&vertices[..3], None, identity);
+        assert!(hit.hit);
+    }
+
+    #[test]
+    fn mesh() {
+        let mut vertices = [0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 2.0, 0.0f32];
+        let mut mesh: Mesh = unsafe { std::mem::zeroed() };
+        mesh.vertexCount = 3;
+        mesh.triangleCount = 1;
+        mesh.vertices = vertices.as_mut_ptr();
+        let hit =
+            unsafe { ray(0.5, 0.5).collide_mesh(&mesh, Matrix::identity()) };
+        assert!(hit.hit);
+        assert!(close(hit.point, v(0.5, 0.5, 0.0)));
+    }
+}
@@ -0,0 +1,174 @@
+//! The C raymath functions, for checking the Rust ports against. raymath's
+//! functions are inline, so bindgen does not generate them; the build script
+//! compiles `raymath_shim.c` to export a copy of each with a `c_` prefix when
+//! the `raymath-c-tests` feature is on. [Rng] is always available
+
+#[cfg(feature = "raymath-c-tests")]
+use std::{ffi::c_int, fmt::Debug};
+
+use crate::Vector3;
+#[cfg(feature = "raymath-c-tests")]
+use crate::{Matrix, Quaternion, Vector2};
+
+#[cfg(feature = "raymath-c-tests")]
+extern "C" {
+    pub fn c_Vector2Add(v1: Vector2, v2: Vector2) -> Vector2;
+    pub fn c_Vector2AddValue(v: Vector2, add: f32) -> Vector2;
+    pub fn c_Vector2Subtract(v1: Vector2, v2: Vector2) -> Vector2;
+    pub fn c_Vector2SubtractValue(v: Vector2, sub: f32) -> Vector2;
+    pub fn c_Vector2Length(v: Vector2) -> f32;
+    pub fn c_Vector2LengthSqr(v: Vector2) -> f32;
+    pub fn c_Vector2DotProduct(v1: Vector2, v2: Vector2) -> f32;
+    pub fn c_Vector2CrossProduct(v1: Vector2, v2: Vector2) -> f32;
+    pub fn c_Vector2Distance(v1: Vector2, v2: Vector2) -> f32;
+    pub fn c_Vector2DistanceSqr(v1: Vector2, v2: Vector2) -> f32;
+    pub fn c_Vector2Angle(v1: Vector2, v2: Vector2) -> f32;
+    pub fn c_Vector2LineAngle(start: Vector2, end: Vector2) -> f32;
+    pub fn c_Vector2Scale(v: Vector2, scale: f32) -> Vector2;
+    pub fn c_Vector2Multiply(v1: Vector2, v2: Vector2) -> Vector2;
+    pub fn c_Vector2Negate(v: Vector2) -> Vector2;
+    pub fn c_Vector2Divide(v1: Vector2, v2: Vector2) -> Vector2;
+    pub fn c_Vector2Normalize(v: Vector2) -> Vector2;
+    pub fn c_Vector2Transform(v: Vector2, mat: Matrix) -> Vector2;
+    pub fn c_Vector2Lerp(v1: Vector2, v2: Vector2, amount: f32) -> Vector2;
+    pub fn c_Vector2Reflect(v: Vector2, normal: Vector2) -> Vector2;
+    pub fn c_Vector2Min(v1: Vector2, v2: Vector2) -> Vector2;
+    pub fn c_Vector2Max(v1: Vector2, v2: Vector2) -> Vector2;
+    pub fn c_Vector2Rotate(v: Vector2, angle: f32) -> Vector2;
+    pub fn c_Vector2MoveTowards(
+        v: Vector2,
+        target: Vector2,
+        max_distance: f32,
+    ) -> Vector2;
+    pub fn c_Vector2Invert(v: Vector2) -> Vector2;
+    pub fn c_Vector2Clamp(v: Vector2, min: Vector2, max: Vector2) -> Vector2;
+    pub fn c_Vector2ClampValue(v: Vector2, min: f32, max: f32) -> Vector2;
+    pub fn c_Vector2Equals(p: Vector2, q: Vector2) -> c_int;
+    pub fn c_Vector2Refract(v: Vector2, n: Vector2, r: f32) -> Vector2;
+
+    pub fn c_Vector3Add(v1: Vector3, v2: Vector3) -> Vector3;
+    pub fn c_Vector3AddValue(v: Vector3, add: f32) -> Vector3;
+    pub fn c_Vector3Subtract(v1: Vector3, v2: Vector3) -> Vector3;
+    pub fn c_Vector3SubtractValue(v: Vector3, sub: f32) -> Vector3;
+    pub fn c_Vector3Scale(v: Vector3, scalar: f32) -> Vector3;
+    pub fn c_Vector3Multiply(v1: Vector3, v2: Vector3) -> Vector3;
+    pub fn c_Vector3CrossProduct(v1: Vector3, v2: Vector3) -> Vector3;
+    pub fn c_Vector3Perpendicular(v: Vector3) -> Vector3;
+    pub fn c_Vector3Length(v: Vector3) -> f32;
+    pub fn c_Vector3LengthSqr(v: Vector3) -> f32;
+    pub fn c_Vector3DotProduct(v1: Vector3, v2: Vector3) -> f32;
+    pub fn c_Vector3Distance(v1: Vector3, v2: Vector3) -> f32;
+    pub fn c_Vector3DistanceSqr(v1: Vector3, v2: Vector3) -> f32;
+    pub fn c_Vector3Angle(v1: Vector3, v2: Vector3) -> f32;
+    pub fn c_Vector3Negate(v: Vector3) -> Vector3;
+    pub fn c_Vector3Divide(v1: Vector3, v2: Vector3) -> Vector3;
+    pub fn c_Vector3Normalize(v: Vector3) -> Vector3;
+    pub fn c_Vector3Project(v1: Vector3, v2: Vector3) -> Vector3;
+    pub fn c_Vector3Reject(v1: Vector3, v2: Vector3) -> Vector3;
+    pub fn c_Vector3OrthoNormalize(v1: *mut Vector3, v2: *mut Vector3);
+    pub fn c_Vector3Transform(v: Vector3, mat: Matrix) -> Vector3;
+    pub fn c_Vector3RotateByQuaternion(v: Vector3, q: Quaternion) -> Vector3;
+    pub fn c_Vector3RotateByAxisAngle(
+        v: Vector3,
+        axis: Vector3,
+        angle: f32,
+    ) -> Vector3;
+    pub fn c_Vector3MoveTowards(
+        v: Vector3,
+        target: Vector3,
+        max_distance: f32,
+    ) -> Vector3;
+    pub fn c_Vector3Lerp(v1: Vector3, v2: Vector3, amount: f32) -> Vector3;
+    pub fn c_Vector3CubicHermite(
+        v1: Vector3,
+        tangent1: Vector3,
+        v2: Vector3,
+        tangent2: Vector3,
+        amount: f32,
+    ) -> Vector3;
+    pub fn c_Vector3Reflect(v: Vector3, normal: Vector3) -> Vector3;
+    pub fn c_Vector3Min(v1: Vector3, v2: Vector3) -> Vector3;
+    pub fn c_Vector3Max(v1: Vector3, v2: Vector3) -> Vector3;
+    pub fn c_Vector3Barycenter(
+        p: Vector3,
+        a: Vector3,
+        b: Vector3,
+        c: Vector3,
+    ) -> Vector3;
+    pub fn c_Vector3Unproject(
+        source: Vector3,
+        projection: Matrix,
+        view: Matrix,
+    ) -> Vector3;
+    pub fn c_Vector3Invert(v: Vector3) -> Vector3;
+    pub fn c_Vector3Clamp(v: Vector3, min: Vector3, max: Vector3) -> Vector3;
+    pub fn c_Vector3ClampValue(v: Vector3, min: f32, max: f32) -> Vector3;
+    pub fn c_Vector3Equals(p: Vector3, q: Vector3) -> c_int;
+    pub fn c_Vector3Refract(v: Vector3, n: Vector3, r: f32) -> Vector3;
+}
+
+/// deterministic xorshift sequence of floats in [-10, 10)
+pub struct Rng(pub u32);
+
+impl Rng {
+    pub fn float(&mut self) -> f32 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 17;
+        self.0 ^= self.0 << 5;
+        (self.0 as f32 / u32::MAX as f32) * 20.0 - 10.0
+    }
+
+    #[cfg(feature = "raymath-c-tests")]
+    pub fn vector2(&mut self) -> Vector2 {
+        Vector2::new(self.float(), self.float())
+    }
+
+    pub fn vector3(&mut self) -> Vector3 {
+        Vector3::new(self.float(), self.float(), self.float())
+    }
+
+    /// a matrix with every element random, not just an affine transform
+    #[cfg(feature = "raymath-c-tests")]
+    pub fn matrix(&mut self) -> Matrix {
+        Matrix::from([(); 16].map(|_| self.float()))
+    }
+}
+
+/// Results that only need to agree to within rounding, since the C compiler
+/// may contract or reorder floating point operations
+#[cfg(feature = "raymath-c-tests")]
+pub trait Close: Copy + Debug {
+    fn close(self, other: Self) -> bool;
+}
+
+#[cfg(feature = "raymath-c-tests")]
+impl Close for f32 {
+    fn close(self, other: f32) -> bool {
+        (self.is_nan() && other.is_nan())
+            || self == other
+            || (self - other).abs()
+                <= 1e-5 * self.abs().max(other.abs()).max(1.0)
+    }
+}
+
+#[cfg(feature = "raymath-c-tests")]
+impl Close for Vector2 {
+    fn close(self, other: Vector2) -> bool {
+        self.x.close(other.x) && self.y.close(other.y)
+    }
+}
+
+#[cfg(feature = "raymath-c-tests")]
+impl Close for Vector3 {
+    fn close(self, other: Vector3) -> bool {
+        self.x.close(other.x) && self.y.close(other.y) && self.z.close(other.z)
+    }
+}
+
+/// assert that the Rust port `rust` of the raymath function `name` matches
+/// the C result `c`
+#[cfg(feature = "raymath-c-tests")]
+#[track_caller]
+pub fn check<T: Close>(name: &str, rust: T, c: T) {
+    assert!(rust.close(c), "{name}: Rust {rust:?} != C {c:?}");
+}
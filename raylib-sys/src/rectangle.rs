@@ -1,7 +1,196 @@
-use crate::Rectangle;
+use crate::{collision, Rectangle, Vector2};
 
 impl Rectangle {
     pub fn new(x: f32, y: f32, width: f32, height: f32) -> Self {
         Self { x, y, width, height }
     }
+
+    /// rectangle of `size` centered on `center`
+    pub fn from_center(center: Vector2, size: Vector2) -> Self {
+        let corner = center - size.scale(0.5);
+        Self::new(corner.x, corner.y, size.x, size.y)
+    }
+
+    /// the top left corner
+    pub fn position(&self) -> Vector2 {
+        Vector2::new(self.x, self.y)
+    }
+
+    pub fn size(&self) -> Vector2 {
+        Vector2::new(self.width, self.height)
+    }
+
+    pub fn center(&self) -> Vector2 {
+        Vector2::new(self.x + self.width * 0.5, self.y + self.height * 0.5)
+    }
+
+    /// x of the right edge
+    pub fn right(&self) -> f32 {
+        self.x + self.width
+    }
+
+    /// y of the bottom edge
+    pub fn bottom(&self) -> f32 {
+        self.y + self.height
+    }
+
+    /// whether `point` is inside, counting the top and left edges but not the
+    /// bottom and right ones, like `CheckCollisionPointRec`
+    pub fn contains_point(&self, point: Vector2) -> bool {
+        point.x >= self.x
+            && point.x < self.right()
+            && point.y >= self.y
+            && point.y < self.bottom()
+    }
+
+    /// whether the rectangles overlap, not counting touching edges, like
+    /// `CheckCollisionRecs`
+    pub fn intersects(&self, other: &Rectangle) -> bool {
+        self.x < other.right()
+            && self.right() > other.x
+            && self.y < other.bottom()
+            && self.bottom() > other.y
+    }
+
+    /// whether the circle overlaps or touches the rectangle, like
+    /// `CheckCollisionCircleRec`
+    pub fn intersects_circle(&self, center: Vector2, radius: f32) -> bool {
+        collision::circle_rec(center, radius, *self)
+    }
+
+    /// the overlapping area, like `GetCollisionRec`, or `None` if the
+    /// rectangles do not [intersect](Rectangle::intersects)
+    pub fn intersection(&self, other: &Rectangle) -> Option<Rectangle> {
+        let left = self.x.max(other.x);
+        let top = self.y.max(other.y);
+        let right = self.right().min(other.right());
+        let bottom = self.bottom().min(other.bottom());
+        (left < right && top < bottom)
+            .then(|| Rectangle::new(left, top, right - left, bottom - top))
+    }
+
+    /// the smallest rectangle containing both
+    pub fn union(&self, other: &Rectangle) -> Rectangle {
+        let left = self.x.min(other.x);
+        let top = self.y.min(other.y);
+        let right = self.right().max(other.right());
+        let bottom = self.bottom().max(other.bottom());
+        Rectangle::new(left, top, right - left, bottom - top)
+    }
+
+    /// move every edge outwards by `amount`, or inwards if it is negative. A
+    /// rectangle shrunk past nothing collapses onto its center
+    pub fn expand(&self, amount: f32) -> Rectangle {
+        let size = self.size() + Vector2::new(amount, amount).scale(2.0);
+        Rectangle::from_center(self.center(), size.max(Vector2::zero()))
+    }
+
+    /// move every edge inwards by `amount`, the opposite of
+    /// [Rectangle::expand]
+    pub fn inset(&self, amount: f32) -> Rectangle {
+        self.expand(-amount)
+    }
+
+    /// split into a grid of `columns` by `rows` equally sized cells, row by
+    /// row from the top left
+    pub fn split(
+        &self,
+        columns: usize,
+        rows: usize,
+    ) -> impl Iterator<Item = Rectangle> {
+        let rec = *self;
+        let width = rec.width / columns as f32;
+        let height = rec.height / rows as f32;
+        (0..rows).flat_map(move |row| {
+            (0..columns).map(move |column| {
+                Rectangle::new(
+                    rec.x + column as f32 * width,
+                    rec.y + row as f32 * height,
+                    width,
+                    height,
+                )
+            })
+        })
+    }
+}
+
+impl PartialEq for Rectangle {
+    fn eq(&self, other: &Self) -> bool {
+        self.x == other.x
+            && self.y == other.y
+            && self.width == other.width
+            && self.height == other.height
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn v(x: f32, y: f32) -> Vector2 {
+        Vector2::new(x, y)
+    }
+
+    #[test]
+    fn accessors() {
+        let rec = Rectangle::new(1.0, 2.0, 4.0, 6.0);
+        assert_eq!(rec.position(), v(1.0, 2.0));
+        assert_eq!(rec.size(), v(4.0, 6.0));
+        assert_eq!(rec.center(), v(3.0, 5.0));
+        assert_eq!((rec.right(), rec.bottom()), (5.0, 8.0));
+        assert_eq!(Rectangle::from_center(v(3.0, 5.0), v(4.0, 6.0)), rec);
+    }
+
+    #[test]
+    fn contains_point() {
+        let rec = Rectangle::new(0.0, 0.0, 2.0, 2.0);
+        assert!(rec.contains_point(v(0.0, 0.0)));
+        assert!(rec.contains_point(v(1.0, 1.9)));
+        assert!(!rec.contains_point(v(2.0, 1.0)));
+        assert!(!rec.contains_point(v(-0.1, 1.0)));
+    }
+
+    #[test]
+    fn intersection_and_union() {
+        let a = Rectangle::new(0.0, 0.0, 4.0, 4.0);
+        let b = Rectangle::new(2.0, 3.0, 4.0, 4.0);
+        assert!(a.intersects(&b));
+        assert_eq!(
+            a.intersection(&b),
+            Some(Rectangle::new(2.0, 3.0, 2.0, 1.0))
+        );
+        assert_eq!(a.union(&b), Rectangle::new(0.0, 0.0, 6.0, 7.0));
+
+        // touching edges do not intersect
+        let c = Rectangle::new(4.0, 0.0, 1.0, 1.0);
+        assert!(!a.intersects(&c));
+        assert_eq!(a.intersection(&c), None);
+        assert_eq!(a.union(&c), Rectangle::new(0.0, 0.0, 5.0, 4.0));
+    }
+
+    #[test]
+    fn expand_and_inset() {
+        let rec = Rectangle::new(0.0, 0.0, 4.0, 2.0);
+        assert_eq!(rec.expand(1.0), Rectangle::new(-1.0, -1.0, 6.0, 4.0));
+        assert_eq!(rec.inset(0.5), Rectangle::new(0.5, 0.5, 3.0, 1.0));
+        assert_eq!(rec.inset(1.5), Rectangle::new(1.5, 1.0, 1.0, 0.0));
+    }
+
+    #[test]
+    fn split() {
+        let rec = Rectangle::new(10.0, 20.0, 30.0, 40.0);
+        let cells: Vec<_> = rec.split(3, 2).collect();
+        assert_eq!(cells.len(), 6);
+        assert_eq!(cells[0], Rectangle::new(10.0, 20.0, 10.0, 20.0));
+        assert_eq!(cells[1], Rectangle::new(20.0, 20.0, 10.0, 20.0));
+        assert_eq!(cells[5], Rectangle::new(30.0, 40.0, 10.0, 20.0));
+        assert_eq!(cells.iter().fold(cells[0], |a, b| a.union(b)), rec);
+    }
+
+    #[test]
+    fn circle() {
+        let rec = Rectangle::new(0.0, 0.0, 2.0, 2.0);
+        assert!(rec.intersects_circle(v(3.0, 1.0), 1.0));
+        assert!(!rec.intersects_circle(v(3.0, 3.0), 1.0));
+    }
 }
@@ -1,7 +1,586 @@
-use crate::Vector2;
+use crate::{Matrix, Vector2, EPSILON};
 
 impl Vector2 {
     pub fn new(x: f32, y: f32) -> Self {
         Self { x, y }
     }
+
+    pub fn zero() -> Self {
+        Vector2Zero()
+    }
+
+    pub fn one() -> Self {
+        Vector2One()
+    }
+
+    pub fn length(self) -> f32 {
+        Vector2Length(self)
+    }
+
+    pub fn length_sqr(self) -> f32 {
+        Vector2LengthSqr(self)
+    }
+
+    pub fn dot(self, v: Vector2) -> f32 {
+        Vector2DotProduct(self, v)
+    }
+
+    /// magnitude of the 3D cross product of `self` and `v` in the XY plane
+    pub fn cross(self, v: Vector2) -> f32 {
+        Vector2CrossProduct(self, v)
+    }
+
+    pub fn distance(self, v: Vector2) -> f32 {
+        Vector2Distance(self, v)
+    }
+
+    pub fn distance_sqr(self, v: Vector2) -> f32 {
+        Vector2DistanceSqr(self, v)
+    }
+
+    /// signed angle in radians from `self` to `v`
+    pub fn angle(self, v: Vector2) -> f32 {
+        Vector2Angle(self, v)
+    }
+
+    /// angle in radians of the line from `self` to `end`, measured clockwise
+    /// from the X axis in screen coordinates
+    pub fn line_angle(self, end: Vector2) -> f32 {
+        Vector2LineAngle(self, end)
+    }
+
+    pub fn scale(self, scale: f32) -> Self {
+        Vector2Scale(self, scale)
+    }
+
+    /// unit vector in the same direction, or zero for a zero vector
+    pub fn normalize(self) -> Self {
+        Vector2Normalize(self)
+    }
+
+    pub fn transform(self, mat: Matrix) -> Self {
+        Vector2Transform(self, mat)
+    }
+
+    /// linear interpolation from `self` at `amount` 0 to `v` at 1
+    pub fn lerp(self, v: Vector2, amount: f32) -> Self {
+        Vector2Lerp(self, v, amount)
+    }
+
+    pub fn reflect(self, normal: Vector2) -> Self {
+        Vector2Reflect(self, normal)
+    }
+
+    pub fn min(self, v: Vector2) -> Self {
+        Vector2Min(self, v)
+    }
+
+    pub fn max(self, v: Vector2) -> Self {
+        Vector2Max(self, v)
+    }
+
+    /// rotate counter-clockwise by `angle` radians
+    pub fn rotate(self, angle: f32) -> Self {
+        Vector2Rotate(self, angle)
+    }
+
+    /// move toward `target` by at most `max_distance`
+    pub fn move_towards(self, target: Vector2, max_distance: f32) -> Self {
+        Vector2MoveTowards(self, target, max_distance)
+    }
+
+    /// component-wise reciprocal
+    pub fn invert(self) -> Self {
+        Vector2Invert(self)
+    }
+
+    /// clamp each component between `min` and `max`
+    pub fn clamp(self, min: Vector2, max: Vector2) -> Self {
+        Vector2Clamp(self, min, max)
+    }
+
+    /// clamp the length between `min` and `max`
+    pub fn clamp_value(self, min: f32, max: f32) -> Self {
+        Vector2ClampValue(self, min, max)
+    }
+
+    /// approximate equality, relative to the size of the components
+    pub fn equals(self, v: Vector2) -> bool {
+        Vector2Equals(self, v)
+    }
+
+    /// refract through a surface with `normal`, where `ratio` is the ratio of
+    /// the refractive indices
+    pub fn refract(self, normal: Vector2, ratio: f32) -> Self {
+        Vector2Refract(self, normal, ratio)
+    }
+}
+
+vector_ops! {
+    Vector2 { x, y },
+    add: Vector2Add,
+    add_value: Vector2AddValue,
+    subtract: Vector2Subtract,
+    subtract_value: Vector2SubtractValue,
+    multiply: Vector2Multiply,
+    scale: Vector2Scale,
+    divide: Vector2Divide,
+    negate: Vector2Negate,
+}
+
+impl From<(f32, f32)> for Vector2 {
+    fn from((x, y): (f32, f32)) -> Self {
+        Self { x, y }
+    }
+}
+
+impl From<[f32; 2]> for Vector2 {
+    fn from([x, y]: [f32; 2]) -> Self {
+        Self { x, y }
+    }
+}
+
+impl From<Vector2> for [f32; 2] {
+    fn from(v: Vector2) -> Self {
+        [v.x, v.y]
+    }
+}
+
+pub fn Vector2Zero() -> Vector2 {
+    Vector2 { x: 0.0, y: 0.0 }
+}
+
+pub fn Vector2One() -> Vector2 {
+    Vector2 { x: 1.0, y: 1.0 }
+}
+
+pub fn Vector2Add(v1: Vector2, v2: Vector2) -> Vector2 {
+    Vector2 { x: v1.x + v2.x, y: v1.y + v2.y }
+}
+
+pub fn Vector2AddValue(v: Vector2, add: f32) -> Vector2 {
+    Vector2 { x: v.x + add, y: v.y + add }
+}
+
+pub fn Vector2Subtract(v1: Vector2, v2: Vector2) -> Vector2 {
+    Vector2 { x: v1.x - v2.x, y: v1.y - v2.y }
+}
+
+pub fn Vector2SubtractValue(v: Vector2, sub: f32) -> Vector2 {
+    Vector2 { x: v.x - sub, y: v.y - sub }
+}
+
+pub fn Vector2Length(v: Vector2) -> f32 {
+    (v.x * v.x + v.y * v.y).sqrt()
+}
+
+pub fn Vector2LengthSqr(v: Vector2) -> f32 {
+    v.x * v.x + v.y * v.y
+}
+
+pub fn Vector2DotProduct(v1: Vector2, v2: Vector2) -> f32 {
+    v1.x * v2.x + v1.y * v2.y
+}
+
+pub fn Vector2CrossProduct(v1: Vector2, v2: Vector2) -> f32 {
+    v1.x * v2.y - v1.y * v2.x
+}
+
+pub fn Vector2Distance(v1: Vector2, v2: Vector2) -> f32 {
+    Vector2DistanceSqr(v1, v2).sqrt()
+}
+
+pub fn Vector2DistanceSqr(v1: Vector2, v2: Vector2) -> f32 {
+    (v1.x - v2.x) * (v1.x - v2.x) + (v1.y - v2.y) * (v1.y - v2.y)
+}
+
+pub fn Vector2Angle(v1: Vector2, v2: Vector2) -> f32 {
+    let dot = v1.x * v2.x + v1.y * v2.y;
+    let det = v1.x * v2.y - v1.y * v2.x;
+    det.atan2(dot)
+}
+
+pub fn Vector2LineAngle(start: Vector2, end: Vector2) -> f32 {
+    -(end.y - start.y).atan2(end.x - start.x)
+}
+
+pub fn Vector2Scale(v: Vector2, scale: f32) -> Vector2 {
+    Vector2 { x: v.x * scale, y: v.y * scale }
+}
+
+pub fn Vector2Multiply(v1: Vector2, v2: Vector2) -> Vector2 {
+    Vector2 { x: v1.x * v2.x, y: v1.y * v2.y }
+}
+
+pub fn Vector2Negate(v: Vector2) -> Vector2 {
+    Vector2 { x: -v.x, y: -v.y }
+}
+
+pub fn Vector2Divide(v1: Vector2, v2: Vector2) -> Vector2 {
+    Vector2 { x: v1.x / v2.x, y: v1.y / v2.y }
+}
+
+pub fn Vector2Normalize(v: Vector2) -> Vector2 {
+    let length = Vector2Length(v);
+    if length > 0.0 {
+        let ilength = 1.0 / length;
+        Vector2 {
+            x: v.x * ilength,
+            y: v.y * ilength,
+        }
+    } else {
+        Vector2Zero()
+    }
+}
+
+pub fn Vector2Transform(v: Vector2, mat: Matrix) -> Vector2 {
+    let Vector2 { x, y } = v;
+    Vector2 {
+        x: mat.m0 * x + mat.m4 * y + mat.m12,
+        y: mat.m1 * x + mat.m5 * y + mat.m13,
+    }
+}
+
+pub fn Vector2Lerp(v1: Vector2, v2: Vector2, amount: f32) -> Vector2 {
+    Vector2 {
+        x: v1.x + amount * (v2.x - v1.x),
+        y: v1.y + amount * (v2.y - v1.y),
+    }
+}
+
+pub fn Vector2Reflect(v: Vector2, normal: Vector2) -> Vector2 {
+    let dot = Vector2DotProduct(v, normal);
+    Vector2 {
+        x: v.x - 2.0 * normal.x * dot,
+        y: v.y - 2.0 * normal.y * dot,
+    }
+}
+
+pub fn Vector2Min(v1: Vector2, v2: Vector2) -> Vector2 {
+    Vector2 {
+        x: v1.x.min(v2.x),
+        y: v1.y.min(v2.y),
+    }
+}
+
+pub fn Vector2Max(v1: Vector2, v2: Vector2) -> Vector2 {
+    Vector2 {
+        x: v1.x.max(v2.x),
+        y: v1.y.max(v2.y),
+    }
+}
+
+pub fn Vector2Rotate(v: Vector2, angle: f32) -> Vector2 {
+    let (sinres, cosres) = angle.sin_cos();
+    Vector2 {
+        x: v.x * cosres - v.y * sinres,
+        y: v.x * sinres + v.y * cosres,
+    }
+}
+
+pub fn Vector2MoveTowards(
+    v: Vector2,
+    target: Vector2,
+    max_distance: f32,
+) -> Vector2 {
+    let dx = target.x - v.x;
+    let dy = target.y - v.y;
+    let value = dx * dx + dy * dy;
+    if value == 0.0
+        || (max_distance >= 0.0 && value <= max_distance * max_distance)
+    {
+        return target;
+    }
+    let dist = value.sqrt();
+    Vector2 {
+        x: v.x + dx / dist * max_distance,
+        y: v.y + dy / dist * max_distance,
+    }
+}
+
+pub fn Vector2Invert(v: Vector2) -> Vector2 {
+    Vector2 { x: 1.0 / v.x, y: 1.0 / v.y }
+}
+
+pub fn Vector2Clamp(v: Vector2, min: Vector2, max: Vector2) -> Vector2 {
+    Vector2 {
+        x: max.x.min(min.x.max(v.x)),
+        y: max.y.min(min.y.max(v.y)),
+    }
+}
+
+pub fn Vector2ClampValue(v: Vector2, min: f32, max: f32) -> Vector2 {
+    let length = Vector2LengthSqr(v);
+    if length <= 0.0 {
+        return v;
+    }
+    let length = length.sqrt();
+    let scale = if length < min {
+        min / length
+    } else if length > max {
+        max / length
+    } else {
+        1.0
+    };
+    Vector2Scale(v, scale)
+}
+
+pub fn Vector2Equals(p: Vector2, q: Vector2) -> bool {
+    let eps = EPSILON as f32;
+    (p.x - q.x).abs() <= eps * 1.0f32.max(p.x.abs().max(q.x.abs()))
+        && (p.y - q.y).abs() <= eps * 1.0f32.max(p.y.abs().max(q.y.abs()))
+}
+
+pub fn Vector2Refract(v: Vector2, n: Vector2, r: f32) -> Vector2 {
+    let dot = Vector2DotProduct(v, n);
+    let d = 1.0 - r * r * (1.0 - dot * dot);
+    if d < 0.0 {
+        return Vector2Zero();
+    }
+    let d = d.sqrt();
+    Vector2 {
+        x: r * v.x - (r * dot + d) * n.x,
+        y: r * v.y - (r * dot + d) * n.y,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::{FRAC_PI_2, FRAC_PI_4};
+
+    use super::*;
+    #[cfg(feature = "raymath-c-tests")]
+    use crate::raymath_c::*;
+
+    // spot checks of known values, then every function against the C
+    // raymath in `matches_raymath`
+
+    fn v(x: f32, y: f32) -> Vector2 {
+        Vector2::new(x, y)
+    }
+
+    #[track_caller]
+    fn assert_close(got: Vector2, want: Vector2) {
+        assert!(
+            (got.x - want.x).abs() < 1e-5 && (got.y - want.y).abs() < 1e-5,
+            "{got:?} != {want:?}"
+        );
+    }
+
+    #[test]
+    fn operators() {
+        let a = v(1.0, 2.0);
+        let b = v(3.0, -4.0);
+        assert_eq!(a + b, v(4.0, -2.0));
+        assert_eq!(a + 1.0, v(2.0, 3.0));
+        assert_eq!(a - b, v(-2.0, 6.0));
+        assert_eq!(a - 1.0, v(0.0, 1.0));
+        assert_eq!(a * b, v(3.0, -8.0));
+        assert_eq!(a * 2.0, v(2.0, 4.0));
+        assert_eq!(2.0 * a, v(2.0, 4.0));
+        assert_eq!(b / a, v(3.0, -2.0));
+        assert_eq!(b / 2.0, v(1.5, -2.0));
+        assert_eq!(-a, v(-1.0, -2.0));
+
+        let mut c = a;
+        c += b;
+        c -= 1.0;
+        c *= 2.0;
+        c /= v(2.0, 1.0);
+        assert_eq!(c, v(3.0, -6.0));
+    }
+
+    #[test]
+    fn conversions() {
+        assert_eq!(Vector2::default(), Vector2::zero());
+        assert_eq!(Vector2::from((1.0, 2.0)), v(1.0, 2.0));
+        assert_eq!(Vector2::from([1.0, 2.0]), v(1.0, 2.0));
+        assert_eq!(<[f32; 2]>::from(v(1.0, 2.0)), [1.0, 2.0]);
+    }
+
+    #[test]
+    fn products_and_lengths() {
+        let a = v(3.0, 4.0);
+        let b = v(-2.0, 1.0);
+        assert_eq!(a.length(), 5.0);
+        assert_eq!(a.length_sqr(), 25.0);
+        assert_eq!(a.dot(b), -2.0);
+        assert_eq!(a.cross(b), 11.0);
+        assert!((a.distance(b) - 34.0f32.sqrt()).abs() < 1e-6);
+        assert_eq!(a.distance_sqr(b), 34.0);
+        assert_close(a.normalize(), v(0.6, 0.8));
+        assert_eq!(Vector2::zero().normalize(), Vector2::zero());
+    }
+
+    #[test]
+    fn angles() {
+        assert!((v(1.0, 0.0).angle(v(0.0, 1.0)) - FRAC_PI_2).abs() < 1e-6);
+        assert!((v(0.0, 1.0).angle(v(1.0, 0.0)) + FRAC_PI_2).abs() < 1e-6);
+        let line = v(0.0, 0.0).line_angle(v(1.0, 1.0));
+        assert!((line + FRAC_PI_4).abs() < 1e-6);
+        assert_close(v(1.0, 0.0).rotate(FRAC_PI_2), v(0.0, 1.0));
+    }
+
+    #[test]
+    fn interpolation() {
+        let a = v(0.0, 10.0);
+        let b = v(10.0, 0.0);
+        assert_eq!(a.lerp(b, 0.25), v(2.5, 7.5));
+        assert_close(a.move_towards(b, 2.0f32.sqrt()), v(1.0, 9.0));
+        assert_eq!(a.move_towards(b, 100.0), b);
+        assert_eq!(a.min(b), v(0.0, 0.0));
+        assert_eq!(a.max(b), v(10.0, 10.0));
+    }
+
+    #[test]
+    fn reflect_and_refract() {
+        let n = v(0.0, 1.0);
+        assert_eq!(v(1.0, -1.0).reflect(n), v(1.0, 1.0));
+        let incoming = v(1.0, -1.0).normalize();
+        assert_close(incoming.refract(n, 1.0), incoming);
+        assert_close(incoming.refract(n, 0.5), v(0.353_553_4, -0.935_414_4));
+        // total internal reflection
+        assert_eq!(incoming.refract(n, 2.0), Vector2::zero());
+    }
+
+    #[test]
+    fn clamping() {
+        let a = v(-5.0, 5.0);
+        assert_eq!(a.clamp(v(-1.0, -1.0), v(1.0, 1.0)), v(-1.0, 1.0));
+        assert_close(v(3.0, 4.0).clamp_value(0.0, 1.0), v(0.6, 0.8));
+        assert_close(v(0.3, 0.4).clamp_value(1.0, 2.0), v(0.6, 0.8));
+        assert_eq!(v(2.0, 4.0).invert(), v(0.5, 0.25));
+    }
+
+    #[test]
+    fn approximate_equality() {
+        assert!(v(1.0, 1000.0).equals(v(1.000_000_1, 1000.0001)));
+        assert!(!v(1.0, 1.0).equals(v(1.001, 1.0)));
+    }
+
+    #[cfg(feature = "raymath-c-tests")]
+    #[test]
+    fn matches_raymath() {
+        let mut rng = Rng(0x2545_f491);
+        for _ in 0..500 {
+            let (a, b, s) = (rng.vector2(), rng.vector2(), rng.float());
+            let (lo, hi) = (a.min(b), a.max(b));
+            let n = b.normalize();
+            let mat = rng.matrix();
+            unsafe {
+                check("Add", Vector2Add(a, b), c_Vector2Add(a, b));
+                check(
+                    "AddValue",
+                    Vector2AddValue(a, s),
+                    c_Vector2AddValue(a, s),
+                );
+                check(
+                    "Subtract",
+                    Vector2Subtract(a, b),
+                    c_Vector2Subtract(a, b),
+                );
+                check(
+                    "SubtractValue",
+                    Vector2SubtractValue(a, s),
+                    c_Vector2SubtractValue(a, s),
+                );
+                check("Length", Vector2Length(a), c_Vector2Length(a));
+                check("LengthSqr", Vector2LengthSqr(a), c_Vector2LengthSqr(a));
+                check(
+                    "DotProduct",
+                    Vector2DotProduct(a, b),
+                    c_Vector2DotProduct(a, b),
+                );
+                check(
+                    "CrossProduct",
+                    Vector2CrossProduct(a, b),
+                    c_Vector2CrossProduct(a, b),
+                );
+                check(
+                    "Distance",
+                    Vector2Distance(a, b),
+                    c_Vector2Distance(a, b),
+                );
+                check(
+                    "DistanceSqr",
+                    Vector2DistanceSqr(a, b),
+                    c_Vector2DistanceSqr(a, b),
+                );
+                check("Angle", Vector2Angle(a, b), c_Vector2Angle(a, b));
+                check(
+                    "LineAngle",
+                    Vector2LineAngle(a, b),
+                    c_Vector2LineAngle(a, b),
+                );
+                check("Scale", Vector2Scale(a, s), c_Vector2Scale(a, s));
+                check(
+                    "Multiply",
+                    Vector2Multiply(a, b),
+                    c_Vector2Multiply(a, b),
+                );
+                check("Negate", Vector2Negate(a), c_Vector2Negate(a));
+                check("Divide", Vector2Divide(a, b), c_Vector2Divide(a, b));
+                check("Normalize", Vector2Normalize(a), c_Vector2Normalize(a));
+                check(
+                    "Transform",
+                    Vector2Transform(a, mat),
+                    c_Vector2Transform(a, mat),
+                );
+                check("Lerp", Vector2Lerp(a, b, s), c_Vector2Lerp(a, b, s));
+                check("Reflect", Vector2Reflect(a, n), c_Vector2Reflect(a, n));
+                check("Min", Vector2Min(a, b), c_Vector2Min(a, b));
+                check("Max", Vector2Max(a, b), c_Vector2Max(a, b));
+                check("Rotate", Vector2Rotate(a, s), c_Vector2Rotate(a, s));
+                check(
+                    "MoveTowards",
+                    Vector2MoveTowards(a, b, s),
+                    c_Vector2MoveTowards(a, b, s),
+                );
+                check("Invert", Vector2Invert(a), c_Vector2Invert(a));
+                check(
+                    "Clamp",
+                    Vector2Clamp(a * 2.0, lo, hi),
+                    c_Vector2Clamp(a * 2.0, lo, hi),
+                );
+                check(
+                    "ClampValue",
+                    Vector2ClampValue(a, s.abs() / 2.0, s.abs()),
+                    c_Vector2ClampValue(a, s.abs() / 2.0, s.abs()),
+                );
+                assert_eq!(Vector2Equals(a, b), c_Vector2Equals(a, b) != 0);
+                assert_eq!(Vector2Equals(a, a), c_Vector2Equals(a, a) != 0);
+                let unit = a.normalize();
+                let r = s / 10.0;
+                check(
+                    "Refract",
+                    Vector2Refract(unit, n, r),
+                    c_Vector2Refract(unit, n, r),
+                );
+            }
+        }
+        let zero = Vector2::zero();
+        unsafe {
+            check(
+                "Normalize",
+                Vector2Normalize(zero),
+                c_Vector2Normalize(zero),
+            );
+            check(
+                "ClampValue",
+                Vector2ClampValue(zero, 1.0, 2.0),
+                c_Vector2ClampValue(zero, 1.0, 2.0),
+            );
+        }
+    }
+
+    #[test]
+    fn transform() {
+        let mut mat: Matrix = unsafe { std::mem::zeroed() };
+        mat.m0 = 2.0;
+        mat.m5 = 3.0;
+        mat.m12 = 1.0;
+        mat.m13 = -1.0;
+        mat.m15 = 1.0;
+        assert_eq!(v(1.0, 1.0).transform(mat), v(3.0, 2.0));
+    }
 }
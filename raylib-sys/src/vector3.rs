@@ -1,15 +1,203 @@
-use std::ops::{Add, Sub};
-
-use crate::{Matrix, Vector3};
+use crate::{
+    matrix::{MatrixInvert, MatrixMultiply},
+    quaternion::QuaternionTransform,
+    Matrix, Quaternion, Vector3, EPSILON,
+};
 
 impl Vector3 {
     pub fn new(x: f32, y: f32, z: f32) -> Self {
         Self { x, y, z }
     }
 
+    pub fn zero() -> Self {
+        Vector3Zero()
+    }
+
+    pub fn one() -> Self {
+        Vector3One()
+    }
+
     pub fn transform(self, mat: Matrix) -> Self {
         Vector3Transform(self, mat)
     }
+
+    pub fn scale(self, scalar: f32) -> Self {
+        Vector3Scale(self, scalar)
+    }
+
+    pub fn cross(self, v: Vector3) -> Self {
+        Vector3CrossProduct(self, v)
+    }
+
+    /// a vector perpendicular to `self`
+    pub fn perpendicular(self) -> Self {
+        Vector3Perpendicular(self)
+    }
+
+    pub fn length(self) -> f32 {
+        Vector3Length(self)
+    }
+
+    pub fn length_sqr(self) -> f32 {
+        Vector3LengthSqr(self)
+    }
+
+    pub fn dot(self, v: Vector3) -> f32 {
+        Vector3DotProduct(self, v)
+    }
+
+    pub fn distance(self, v: Vector3) -> f32 {
+        Vector3Distance(self, v)
+    }
+
+    pub fn distance_sqr(self, v: Vector3) -> f32 {
+        Vector3DistanceSqr(self, v)
+    }
+
+    /// unsigned angle in radians between `self` and `v`
+    pub fn angle(self, v: Vector3) -> f32 {
+        Vector3Angle(self, v)
+    }
+
+    /// linear interpolation from `self` at `amount` 0 to `v` at 1
+    pub fn lerp(self, v: Vector3, amount: f32) -> Self {
+        Vector3Lerp(self, v, amount)
+    }
+
+    /// unit vector in the same direction, or `self` for a zero vector
+    pub fn normalize(self) -> Self {
+        Vector3Normalize(self)
+    }
+
+    /// the component of `self` parallel to `v`
+    pub fn project(self, v: Vector3) -> Self {
+        Vector3Project(self, v)
+    }
+
+    /// the component of `self` perpendicular to `v`
+    pub fn reject(self, v: Vector3) -> Self {
+        Vector3Reject(self, v)
+    }
+
+    /// make `v1` and `v2` normalized and orthogonal to each other, keeping
+    /// the direction of `v1`
+    pub fn ortho_normalize(v1: &mut Vector3, v2: &mut Vector3) {
+        Vector3OrthoNormalize(v1, v2)
+    }
+
+    pub fn rotate_by_quaternion(self, q: Quaternion) -> Self {
+        Vector3RotateByQuaternion(self, q)
+    }
+
+    /// rotate by `angle` radians around `axis`
+    pub fn rotate_by_axis_angle(self, axis: Vector3, angle: f32) -> Self {
+        Vector3RotateByAxisAngle(self, axis, angle)
+    }
+
+    /// move toward `target` by at most `max_distance`
+    pub fn move_towards(self, target: Vector3, max_distance: f32) -> Self {
+        Vector3MoveTowards(self, target, max_distance)
+    }
+
+    /// cubic hermite interpolation from `self` with `tangent1` at `amount` 0
+    /// to `v2` with `tangent2` at 1
+    pub fn cubic_hermite(
+        self,
+        tangent1: Vector3,
+        v2: Vector3,
+        tangent2: Vector3,
+        amount: f32,
+    ) -> Self {
+        Vector3CubicHermite(self, tangent1, v2, tangent2, amount)
+    }
+
+    pub fn reflect(self, normal: Vector3) -> Self {
+        Vector3Reflect(self, normal)
+    }
+
+    pub fn min(self, v: Vector3) -> Self {
+        Vector3Min(self, v)
+    }
+
+    pub fn max(self, v: Vector3) -> Self {
+        Vector3Max(self, v)
+    }
+
+    /// barycentric coordinates of `self` with respect to the triangle `a`,
+    /// `b`, `c`
+    pub fn barycenter(self, a: Vector3, b: Vector3, c: Vector3) -> Self {
+        Vector3Barycenter(self, a, b, c)
+    }
+
+    /// project `self` from normalized device coordinates back into world
+    /// space
+    pub fn unproject(self, projection: Matrix, view: Matrix) -> Self {
+        Vector3Unproject(self, projection, view)
+    }
+
+    /// component-wise reciprocal
+    pub fn invert(self) -> Self {
+        Vector3Invert(self)
+    }
+
+    /// clamp each component between `min` and `max`
+    pub fn clamp(self, min: Vector3, max: Vector3) -> Self {
+        Vector3Clamp(self, min, max)
+    }
+
+    /// clamp the length between `min` and `max`
+    pub fn clamp_value(self, min: f32, max: f32) -> Self {
+        Vector3ClampValue(self, min, max)
+    }
+
+    /// approximate equality, relative to the size of the components
+    pub fn equals(self, v: Vector3) -> bool {
+        Vector3Equals(self, v)
+    }
+
+    /// refract through a surface with `normal`, where `ratio` is the ratio of
+    /// the refractive indices
+    pub fn refract(self, normal: Vector3, ratio: f32) -> Self {
+        Vector3Refract(self, normal, ratio)
+    }
+}
+
+vector_ops! {
+    Vector3 { x, y, z },
+    add: Vector3Add,
+    add_value: Vector3AddValue,
+    subtract: Vector3Subtract,
+    subtract_value: Vector3SubtractValue,
+    multiply: Vector3Multiply,
+    scale: Vector3Scale,
+    divide: Vector3Divide,
+    negate: Vector3Negate,
+}
+
+impl From<(f32, f32, f32)> for Vector3 {
+    fn from((x, y, z): (f32, f32, f32)) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl From<[f32; 3]> for Vector3 {
+    fn from([x, y, z]: [f32; 3]) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl From<Vector3> for [f32; 3] {
+    fn from(v: Vector3) -> Self {
+        [v.x, v.y, v.z]
+    }
+}
+
+pub fn Vector3Zero() -> Vector3 {
+    Vector3 { x: 0.0, y: 0.0, z: 0.0 }
+}
+
+pub fn Vector3One() -> Vector3 {
+    Vector3 { x: 1.0, y: 1.0, z: 1.0 }
 }
 
 pub fn Vector3Add(v1: Vector3, v2: Vector3) -> Vector3 {
@@ -20,11 +208,11 @@ pub fn Vector3Add(v1: Vector3, v2: Vector3) -> Vector3 {
     }
 }
 
-impl Add<Vector3> for Vector3 {
-    type Output = Vector3;
-
-    fn add(self, rhs: Vector3) -> Self::Output {
-        Vector3Add(self, rhs)
+pub fn Vector3AddValue(v: Vector3, add: f32) -> Vector3 {
+    Vector3 {
+        x: v.x + add,
+        y: v.y + add,
+        z: v.z + add,
     }
 }
 
@@ -36,12 +224,113 @@ pub fn Vector3Subtract(v1: Vector3, v2: Vector3) -> Vector3 {
     }
 }
 
-impl Sub<Vector3> for Vector3 {
-    type Output = Self;
+pub fn Vector3SubtractValue(v: Vector3, sub: f32) -> Vector3 {
+    Vector3 {
+        x: v.x - sub,
+        y: v.y - sub,
+        z: v.z - sub,
+    }
+}
+
+pub fn Vector3Scale(v: Vector3, scalar: f32) -> Vector3 {
+    Vector3 {
+        x: v.x * scalar,
+        y: v.y * scalar,
+        z: v.z * scalar,
+    }
+}
+
+pub fn Vector3Multiply(v1: Vector3, v2: Vector3) -> Vector3 {
+    Vector3 {
+        x: v1.x * v2.x,
+        y: v1.y * v2.y,
+        z: v1.z * v2.z,
+    }
+}
+
+pub fn Vector3CrossProduct(v1: Vector3, v2: Vector3) -> Vector3 {
+    Vector3 {
+        x: v1.y * v2.z - v1.z * v2.y,
+        y: v1.z * v2.x - v1.x * v2.z,
+        z: v1.x * v2.y - v1.y * v2.x,
+    }
+}
 
-    fn sub(self, rhs: Vector3) -> Self::Output {
-        Vector3Subtract(self, rhs)
+pub fn Vector3Perpendicular(v: Vector3) -> Vector3 {
+    let mut min = v.x.abs();
+    let mut cardinal_axis = Vector3::new(1.0, 0.0, 0.0);
+    if v.y.abs() < min {
+        min = v.y.abs();
+        cardinal_axis = Vector3::new(0.0, 1.0, 0.0);
     }
+    if v.z.abs() < min {
+        cardinal_axis = Vector3::new(0.0, 0.0, 1.0);
+    }
+    Vector3CrossProduct(v, cardinal_axis)
+}
+
+pub fn Vector3Length(v: Vector3) -> f32 {
+    (v.x * v.x + v.y * v.y + v.z * v.z).sqrt()
+}
+
+pub fn Vector3LengthSqr(v: Vector3) -> f32 {
+    v.x * v.x + v.y * v.y + v.z * v.z
+}
+
+pub fn Vector3DotProduct(v1: Vector3, v2: Vector3) -> f32 {
+    v1.x * v2.x + v1.y * v2.y + v1.z * v2.z
+}
+
+pub fn Vector3Distance(v1: Vector3, v2: Vector3) -> f32 {
+    Vector3DistanceSqr(v1, v2).sqrt()
+}
+
+pub fn Vector3DistanceSqr(v1: Vector3, v2: Vector3) -> f32 {
+    let dx = v2.x - v1.x;
+    let dy = v2.y - v1.y;
+    let dz = v2.z - v1.z;
+    dx * dx + dy * dy + dz * dz
+}
+
+pub fn Vector3Angle(v1: Vector3, v2: Vector3) -> f32 {
+    let len = Vector3Length(Vector3CrossProduct(v1, v2));
+    let dot = Vector3DotProduct(v1, v2);
+    len.atan2(dot)
+}
+
+pub fn Vector3Negate(v: Vector3) -> Vector3 {
+    Vector3 { x: -v.x, y: -v.y, z: -v.z }
+}
+
+pub fn Vector3Divide(v1: Vector3, v2: Vector3) -> Vector3 {
+    Vector3 {
+        x: v1.x / v2.x,
+        y: v1.y / v2.y,
+        z: v1.z / v2.z,
+    }
+}
+
+pub fn Vector3Normalize(v: Vector3) -> Vector3 {
+    let length = Vector3Length(v);
+    if length == 0.0 {
+        return v;
+    }
+    Vector3Scale(v, 1.0 / length)
+}
+
+pub fn Vector3Project(v1: Vector3, v2: Vector3) -> Vector3 {
+    let mag = Vector3DotProduct(v1, v2) / Vector3DotProduct(v2, v2);
+    Vector3Scale(v2, mag)
+}
+
+pub fn Vector3Reject(v1: Vector3, v2: Vector3) -> Vector3 {
+    Vector3Subtract(v1, Vector3Project(v1, v2))
+}
+
+pub fn Vector3OrthoNormalize(v1: &mut Vector3, v2: &mut Vector3) {
+    *v1 = Vector3Normalize(*v1);
+    let vn1 = Vector3Normalize(Vector3CrossProduct(*v1, *v2));
+    *v2 = Vector3CrossProduct(vn1, *v1);
 }
 
 pub fn Vector3Transform(v: Vector3, mat: Matrix) -> Vector3 {
@@ -52,3 +341,550 @@ pub fn Vector3Transform(v: Vector3, mat: Matrix) -> Vector3 {
         z: mat.m2 * x + mat.m6 * y + mat.m10 * z + mat.m14,
     }
 }
+
+pub fn Vector3RotateByQuaternion(v: Vector3, q: Quaternion) -> Vector3 {
+    let Quaternion { x: qx, y: qy, z: qz, w: qw } = q;
+    Vector3 {
+        x: v.x * (qx * qx + qw * qw - qy * qy - qz * qz)
+            + v.y * (2.0 * qx * qy - 2.0 * qw * qz)
+            + v.z * (2.0 * qx * qz + 2.0 * qw * qy),
+        y: v.x * (2.0 * qw * qz + 2.0 * qx * qy)
+            + v.y * (qw * qw - qx * qx + qy * qy - qz * qz)
+            + v.z * (-2.0 * qw * qx + 2.0 * qy * qz),
+        z: v.x * (-2.0 * qw * qy + 2.0 * qx * qz)
+            + v.y * (2.0 * qw * qx + 2.0 * qy * qz)
+            + v.z * (qw * qw - qx * qx - qy * qy + qz * qz),
+    }
+}
+
+pub fn Vector3RotateByAxisAngle(
+    v: Vector3,
+    axis: Vector3,
+    angle: f32,
+) -> Vector3 {
+    // Euler-Rodrigues formula
+    let axis = Vector3Normalize(axis);
+    let (sin, cos) = (angle / 2.0).sin_cos();
+    let w = Vector3Scale(axis, sin);
+    let wv = Vector3CrossProduct(w, v);
+    let wwv = Vector3CrossProduct(w, wv);
+    Vector3Add(
+        v,
+        Vector3Add(Vector3Scale(wv, 2.0 * cos), Vector3Scale(wwv, 2.0)),
+    )
+}
+
+pub fn Vector3MoveTowards(
+    v: Vector3,
+    target: Vector3,
+    max_distance: f32,
+) -> Vector3 {
+    let dx = target.x - v.x;
+    let dy = target.y - v.y;
+    let dz = target.z - v.z;
+    let value = dx * dx + dy * dy + dz * dz;
+    if value == 0.0
+        || (max_distance >= 0.0 && value <= max_distance * max_distance)
+    {
+        return target;
+    }
+    let dist = value.sqrt();
+    Vector3 {
+        x: v.x + dx / dist * max_distance,
+        y: v.y + dy / dist * max_distance,
+        z: v.z + dz / dist * max_distance,
+    }
+}
+
+pub fn Vector3Lerp(v1: Vector3, v2: Vector3, amount: f32) -> Vector3 {
+    Vector3 {
+        x: v1.x + amount * (v2.x - v1.x),
+        y: v1.y + amount * (v2.y - v1.y),
+        z: v1.z + amount * (v2.z - v1.z),
+    }
+}
+
+pub fn Vector3CubicHermite(
+    v1: Vector3,
+    tangent1: Vector3,
+    v2: Vector3,
+    tangent2: Vector3,
+    amount: f32,
+) -> Vector3 {
+    let amount2 = amount * amount;
+    let amount3 = amount * amount * amount;
+    let h00 = 2.0 * amount3 - 3.0 * amount2 + 1.0;
+    let h10 = amount3 - 2.0 * amount2 + amount;
+    let h01 = -2.0 * amount3 + 3.0 * amount2;
+    let h11 = amount3 - amount2;
+    Vector3 {
+        x: h00 * v1.x + h10 * tangent1.x + h01 * v2.x + h11 * tangent2.x,
+        y: h00 * v1.y + h10 * tangent1.y + h01 * v2.y + h11 * tangent2.y,
+        z: h00 * v1.z + h10 * tangent1.z + h01 * v2.z + h11 * tangent2.z,
+    }
+}
+
+pub fn Vector3Reflect(v: Vector3, normal: Vector3) -> Vector3 {
+    let dot = Vector3DotProduct(v, normal);
+    Vector3 {
+        x: v.x - 2.0 * normal.x * dot,
+        y: v.y - 2.0 * normal.y * dot,
+        z: v.z - 2.0 * normal.z * dot,
+    }
+}
+
+pub fn Vector3Min(v1: Vector3, v2: Vector3) -> Vector3 {
+    Vector3 {
+        x: v1.x.min(v2.x),
+        y: v1.y.min(v2.y),
+        z: v1.z.min(v2.z),
+    }
+}
+
+pub fn Vector3Max(v1: Vector3, v2: Vector3) -> Vector3 {
+    Vector3 {
+        x: v1.x.max(v2.x),
+        y: v1.y.max(v2.y),
+        z: v1.z.max(v2.z),
+    }
+}
+
+pub fn Vector3Barycenter(
+    p: Vector3,
+    a: Vector3,
+    b: Vector3,
+    c: Vector3,
+) -> Vector3 {
+    let v0 = Vector3Subtract(b, a);
+    let v1 = Vector3Subtract(c, a);
+    let v2 = Vector3Subtract(p, a);
+    let d00 = Vector3DotProduct(v0, v0);
+    let d01 = Vector3DotProduct(v0, v1);
+    let d11 = Vector3DotProduct(v1, v1);
+    let d20 = Vector3DotProduct(v2, v0);
+    let d21 = Vector3DotProduct(v2, v1);
+    let denom = d00 * d11 - d01 * d01;
+    let y = (d11 * d20 - d01 * d21) / denom;
+    let z = (d00 * d21 - d01 * d20) / denom;
+    Vector3 { x: 1.0 - (z + y), y, z }
+}
+
+pub fn Vector3Unproject(
+    source: Vector3,
+    projection: Matrix,
+    view: Matrix,
+) -> Vector3 {
+    let view_projection = MatrixMultiply(view, projection);
+    let inverse = MatrixInvert(view_projection);
+    let quat = Quaternion {
+        x: source.x,
+        y: source.y,
+        z: source.z,
+        w: 1.0,
+    };
+    let q = QuaternionTransform(quat, inverse);
+    Vector3 {
+        x: q.x / q.w,
+        y: q.y / q.w,
+        z: q.z / q.w,
+    }
+}
+
+pub fn Vector3Invert(v: Vector3) -> Vector3 {
+    Vector3 {
+        x: 1.0 / v.x,
+        y: 1.0 / v.y,
+        z: 1.0 / v.z,
+    }
+}
+
+pub fn Vector3Clamp(v: Vector3, min: Vector3, max: Vector3) -> Vector3 {
+    Vector3 {
+        x: max.x.min(min.x.max(v.x)),
+        y: max.y.min(min.y.max(v.y)),
+        z: max.z.min(min.z.max(v.z)),
+    }
+}
+
+pub fn Vector3ClampValue(v: Vector3, min: f32, max: f32) -> Vector3 {
+    let length = Vector3LengthSqr(v);
+    if length <= 0.0 {
+        return v;
+    }
+    let length = length.sqrt();
+    let scale = if length < min {
+        min / length
+    } else if length > max {
+        max / length
+    } else {
+        1.0
+    };
+    Vector3Scale(v, scale)
+}
+
+pub fn Vector3Equals(p: Vector3, q: Vector3) -> bool {
+    let eps = EPSILON as f32;
+    let close = |a: f32, b: f32| {
+        (a - b).abs() <= eps * 1.0f32.max(a.abs().max(b.abs()))
+    };
+    close(p.x, q.x) && close(p.y, q.y) && close(p.z, q.z)
+}
+
+pub fn Vector3Refract(v: Vector3, n: Vector3, r: f32) -> Vector3 {
+    let dot = Vector3DotProduct(v, n);
+    let d = 1.0 - r * r * (1.0 - dot * dot);
+    if d < 0.0 {
+        return Vector3Zero();
+    }
+    let d = d.sqrt();
+    Vector3Subtract(Vector3Scale(v, r), Vector3Scale(n, r * dot + d))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::FRAC_PI_2;
+
+    use super::*;
+    use crate::{matrix::MatrixRotate, raymath_c::*};
+
+    // spot checks of known values and properties, then every function
+    // against the C raymath in `matches_raymath`
+
+    fn samples() -> impl Iterator<Item = (Vector3, Vector3, f32)> {
+        let mut rng = Rng(0x2545_f491);
+        (0..500).map(move |_| (rng.vector3(), rng.vector3(), rng.float()))
+    }
+
+    #[track_caller]
+    fn assert_close(got: Vector3, want: Vector3, tolerance: f32) {
+        assert!(
+            (got - want).length() <= tolerance * want.length().max(1.0),
+            "{got:?} != {want:?}"
+        );
+    }
+
+    fn v(x: f32, y: f32, z: f32) -> Vector3 {
+        Vector3::new(x, y, z)
+    }
+
+    /// perspective projection with fovy 90, aspect 1, near 1, far 100
+    /// looking down -Z from the origin, as from MatrixPerspective
+    fn perspective() -> Matrix {
+        let mut projection: Matrix = unsafe { std::mem::zeroed() };
+        let (near, far) = (1.0, 100.0);
+        projection.m0 = 1.0;
+        projection.m5 = 1.0;
+        projection.m10 = -(far + near) / (far - near);
+        projection.m11 = -1.0;
+        projection.m14 = -2.0 * far * near / (far - near);
+        projection
+    }
+
+    #[test]
+    fn operators() {
+        let a = v(1.0, 2.0, 3.0);
+        let b = v(4.0, -2.0, 0.5);
+        assert_eq!(a + b, v(5.0, 0.0, 3.5));
+        assert_eq!(a + 1.0, v(2.0, 3.0, 4.0));
+        assert_eq!(a - b, v(-3.0, 4.0, 2.5));
+        assert_eq!(a - 1.0, v(0.0, 1.0, 2.0));
+        assert_eq!(a * b, v(4.0, -4.0, 1.5));
+        assert_eq!(a * 2.0, v(2.0, 4.0, 6.0));
+        assert_eq!(2.0 * a, v(2.0, 4.0, 6.0));
+        assert_eq!(b / a, v(4.0, -1.0, 0.5 / 3.0));
+        assert_eq!(a / 2.0, v(0.5, 1.0, 1.5));
+        assert_eq!(-a, v(-1.0, -2.0, -3.0));
+
+        let mut c = a;
+        c += b;
+        c -= 1.0;
+        c *= v(2.0, 1.0, 2.0);
+        c /= 2.0;
+        assert_eq!(c, v(4.0, -0.5, 2.5));
+        assert_eq!(Vector3::default(), Vector3::zero());
+        assert_eq!(Vector3::from((1.0, 2.0, 3.0)), a);
+        assert_eq!(Vector3::from([1.0, 2.0, 3.0]), a);
+        assert_eq!(<[f32; 3]>::from(a), [1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn known_values() {
+        let a = v(1.0, 2.0, 2.0);
+        let b = v(0.0, 3.0, -4.0);
+        assert_eq!(a.length(), 3.0);
+        assert_eq!(a.length_sqr(), 9.0);
+        assert_eq!(a.dot(b), -2.0);
+        assert_eq!(a.cross(b), v(-14.0, 4.0, 3.0));
+        assert_eq!(a.distance_sqr(b), 38.0);
+        assert_eq!(a.perpendicular(), v(0.0, 2.0, -2.0));
+        assert!(
+            (v(1.0, 0.0, 0.0).angle(v(0.0, 0.0, 1.0)) - FRAC_PI_2).abs() < 1e-6
+        );
+        assert_eq!(a.min(b), v(0.0, 2.0, -4.0));
+        assert_eq!(a.max(b), v(1.0, 3.0, 2.0));
+        assert_eq!(a.lerp(b, 0.5), v(0.5, 2.5, -1.0));
+        assert_eq!(
+            v(1.0, -1.0, 0.0).reflect(v(0.0, 1.0, 0.0)),
+            v(1.0, 1.0, 0.0)
+        );
+        assert_eq!(a.project(v(0.0, 0.0, 2.0)), v(0.0, 0.0, 2.0));
+        assert_eq!(a.reject(v(0.0, 0.0, 2.0)), v(1.0, 2.0, 0.0));
+        assert_eq!(v(2.0, 4.0, 0.5).invert(), v(0.5, 0.25, 2.0));
+        assert_eq!(
+            v(-5.0, 0.5, 5.0).clamp(Vector3::zero(), Vector3::one()),
+            v(0.0, 0.5, 1.0)
+        );
+        assert_eq!(Vector3::zero().normalize(), Vector3::zero());
+        assert!(v(1.0, 2.0, 3.0).equals(v(1.000_000_1, 2.0, 3.0)));
+        assert!(!v(1.0, 2.0, 3.0).equals(v(1.001, 2.0, 3.0)));
+    }
+
+    #[test]
+    fn move_towards_and_hermite() {
+        let a = Vector3::zero();
+        let b = v(3.0, 4.0, 0.0);
+        assert_close(a.move_towards(b, 1.0), v(0.6, 0.8, 0.0), 1e-6);
+        assert_eq!(a.move_towards(b, 10.0), b);
+        let t = Vector3::zero();
+        assert_eq!(a.cubic_hermite(t, b, t, 0.0), a);
+        assert_eq!(a.cubic_hermite(t, b, t, 1.0), b);
+        assert_eq!(a.cubic_hermite(t, b, t, 0.5), v(1.5, 2.0, 0.0));
+    }
+
+    #[test]
+    fn rotations_agree() {
+        for (p, axis, angle) in samples() {
+            let by_axis = p.rotate_by_axis_angle(axis, angle);
+            let q = Quaternion::from_axis_angle(axis, angle);
+            assert_close(p.rotate_by_quaternion(q), by_axis, 1e-4);
+            assert_close(p.transform(MatrixRotate(axis, angle)), by_axis, 1e-4);
+            assert!((by_axis.length() - p.length()).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn products() {
+        for (a, b, _) in samples() {
+            let cross = a.cross(b);
+            assert!(cross.dot(a).abs() < 1e-2);
+            assert!(cross.dot(b).abs() < 1e-2);
+            assert_close(a.project(b) + a.reject(b), a, 1e-5);
+            assert!(a.reject(b).dot(b).abs() < 1e-2);
+            assert!(a.perpendicular().dot(a).abs() < 1e-3);
+            assert!((a.normalize().length() - 1.0).abs() < 1e-5);
+            assert!((a.distance(b) - (a - b).length()).abs() < 1e-4);
+            let angle = a.angle(b);
+            let cos = a.dot(b) / (a.length() * b.length());
+            assert!((angle.cos() - cos).abs() < 1e-4);
+
+            let (mut x, mut y) = (a, b);
+            Vector3::ortho_normalize(&mut x, &mut y);
+            assert!((x.length() - 1.0).abs() < 1e-5);
+            assert!((y.length() - 1.0).abs() < 1e-5);
+            assert!(x.dot(y).abs() < 1e-5);
+            assert!(x.angle(a) < 1e-3);
+        }
+    }
+
+    #[test]
+    fn reflect_refract_clamp() {
+        for (a, n, r) in samples() {
+            let n = n.normalize();
+            let reflected = a.reflect(n);
+            assert!((reflected.length() - a.length()).abs() < 1e-3);
+            assert!((reflected.dot(n) + a.dot(n)).abs() < 1e-3);
+            // refraction expects the normal to face the incoming vector
+            let facing = if a.dot(n) > 0.0 { -n } else { n };
+            let unit = a.normalize();
+            assert_close(unit.refract(facing, 1.0), unit, 1e-3);
+            let max = r.abs();
+            assert!(a.clamp_value(0.0, max).length() <= max + 1e-4);
+        }
+    }
+
+    #[test]
+    fn barycenter_reconstructs_point() {
+        let mut rng = Rng(7);
+        for _ in 0..500 {
+            let (a, b, c) = (rng.vector3(), rng.vector3(), rng.vector3());
+            let (u, w) = (rng.float().abs() / 20.0, rng.float().abs() / 20.0);
+            let p = a * (1.0 - u - w) + b * u + c * w;
+            let bary = p.barycenter(a, b, c);
+            let got = a * bary.x + b * bary.y + c * bary.z;
+            assert_close(got, p, 1e-2);
+        }
+    }
+
+    #[test]
+    fn unproject_inverts_projection() {
+        let projection = perspective();
+        let view = MatrixRotate(v(0.0, 1.0, 0.0), 0.0);
+
+        let world = v(2.0, -1.0, -5.0);
+        let clip = QuaternionTransform(
+            Quaternion {
+                x: world.x,
+                y: world.y,
+                z: world.z,
+                w: 1.0,
+            },
+            projection,
+        );
+        let ndc = v(clip.x / clip.w, clip.y / clip.w, clip.z / clip.w);
+        assert_close(ndc.unproject(projection, view), world, 1e-4);
+    }
+
+    #[cfg(feature = "raymath-c-tests")]
+    #[test]
+    fn matches_raymath() {
+        let mut rng = Rng(0x2545_f491);
+        for _ in 0..500 {
+            let (a, b, c) = (rng.vector3(), rng.vector3(), rng.vector3());
+            let s = rng.float();
+            let (lo, hi) = (a.min(b), a.max(b));
+            let n = b.normalize();
+            let mat = rng.matrix();
+            let q = Quaternion::from_axis_angle(c, s);
+            let view = MatrixRotate(c, s);
+            unsafe {
+                check("Add", Vector3Add(a, b), c_Vector3Add(a, b));
+                check(
+                    "AddValue",
+                    Vector3AddValue(a, s),
+                    c_Vector3AddValue(a, s),
+                );
+                check(
+                    "Subtract",
+                    Vector3Subtract(a, b),
+                    c_Vector3Subtract(a, b),
+                );
+                check(
+                    "SubtractValue",
+                    Vector3SubtractValue(a, s),
+                    c_Vector3SubtractValue(a, s),
+                );
+                check("Scale", Vector3Scale(a, s), c_Vector3Scale(a, s));
+                check(
+                    "Multiply",
+                    Vector3Multiply(a, b),
+                    c_Vector3Multiply(a, b),
+                );
+                check(
+                    "CrossProduct",
+                    Vector3CrossProduct(a, b),
+                    c_Vector3CrossProduct(a, b),
+                );
+                check(
+                    "Perpendicular",
+                    Vector3Perpendicular(a),
+                    c_Vector3Perpendicular(a),
+                );
+                check("Length", Vector3Length(a), c_Vector3Length(a));
+                check("LengthSqr", Vector3LengthSqr(a), c_Vector3LengthSqr(a));
+                check(
+                    "DotProduct",
+                    Vector3DotProduct(a, b),
+                    c_Vector3DotProduct(a, b),
+                );
+                check(
+                    "Distance",
+                    Vector3Distance(a, b),
+                    c_Vector3Distance(a, b),
+                );
+                check(
+                    "DistanceSqr",
+                    Vector3DistanceSqr(a, b),
+                    c_Vector3DistanceSqr(a, b),
+                );
+                check("Angle", Vector3Angle(a, b), c_Vector3Angle(a, b));
+                check("Negate", Vector3Negate(a), c_Vector3Negate(a));
+                check("Divide", Vector3Divide(a, b), c_Vector3Divide(a, b));
+                check("Normalize", Vector3Normalize(a), c_Vector3Normalize(a));
+                check("Project", Vector3Project(a, b), c_Vector3Project(a, b));
+                check("Reject", Vector3Reject(a, b), c_Vector3Reject(a, b));
+
+                let (mut x, mut y) = (a, b);
+                Vector3OrthoNormalize(&mut x, &mut y);
+                let (mut cx, mut cy) = (a, b);
+                c_Vector3OrthoNormalize(&mut cx, &mut cy);
+                check("OrthoNormalize", x, cx);
+                check("OrthoNormalize", y, cy);
+
+                check(
+                    "Transform",
+                    Vector3Transform(a, mat),
+                    c_Vector3Transform(a, mat),
+                );
+                check(
+                    "RotateByQuaternion",
+                    Vector3RotateByQuaternion(a, q),
+                    c_Vector3RotateByQuaternion(a, q),
+                );
+                check(
+                    "RotateByAxisAngle",
+                    Vector3RotateByAxisAngle(a, c, s),
+                    c_Vector3RotateByAxisAngle(a, c, s),
+                );
+                check(
+                    "MoveTowards",
+                    Vector3MoveTowards(a, b, s),
+                    c_Vector3MoveTowards(a, b, s),
+                );
+                check("Lerp", Vector3Lerp(a, b, s), c_Vector3Lerp(a, b, s));
+                let t = s / 10.0;
+                check(
+                    "CubicHermite",
+                    Vector3CubicHermite(a, b, c, n, t),
+                    c_Vector3CubicHermite(a, b, c, n, t),
+                );
+                check("Reflect", Vector3Reflect(a, n), c_Vector3Reflect(a, n));
+                check("Min", Vector3Min(a, b), c_Vector3Min(a, b));
+                check("Max", Vector3Max(a, b), c_Vector3Max(a, b));
+                let p = a.lerp(b, t).lerp(c, t);
+                check(
+                    "Barycenter",
+                    Vector3Barycenter(p, a, b, c),
+                    c_Vector3Barycenter(p, a, b, c),
+                );
+                let ndc = a.scale(0.1);
+                check(
+                    "Unproject",
+                    Vector3Unproject(ndc, perspective(), view),
+                    c_Vector3Unproject(ndc, perspective(), view),
+                );
+                check("Invert", Vector3Invert(a), c_Vector3Invert(a));
+                check(
+                    "Clamp",
+                    Vector3Clamp(a * 2.0, lo, hi),
+                    c_Vector3Clamp(a * 2.0, lo, hi),
+                );
+                check(
+                    "ClampValue",
+                    Vector3ClampValue(a, s.abs() / 2.0, s.abs()),
+                    c_Vector3ClampValue(a, s.abs() / 2.0, s.abs()),
+                );
+                assert_eq!(Vector3Equals(a, b), c_Vector3Equals(a, b) != 0);
+                assert_eq!(Vector3Equals(a, a), c_Vector3Equals(a, a) != 0);
+                let unit = a.normalize();
+                check(
+                    "Refract",
+                    Vector3Refract(unit, n, t),
+                    c_Vector3Refract(unit, n, t),
+                );
+            }
+        }
+        let zero = Vector3::zero();
+        unsafe {
+            check(
+                "Normalize",
+                Vector3Normalize(zero),
+                c_Vector3Normalize(zero),
+            );
+            check(
+                "ClampValue",
+                Vector3ClampValue(zero, 1.0, 2.0),
+                c_Vector3ClampValue(zero, 1.0, 2.0),
+            );
+        }
+    }
+}
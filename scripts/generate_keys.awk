@@ -1,9 +1,54 @@
 #!/usr/bin/awk -f
 
-BEGIN {
-	split("abcdefghijklmnopqrstuvwxyz", chars, "")
-	for (c in chars) {
-		char = toupper(chars[c])
-		printf "%s = raylib_sys::KeyboardKey_KEY_%s as i32,\n", char, char
+# Generate src/keys.rs from the KeyboardKey enum in raylib.h:
+#
+#     awk -f scripts/generate_keys.awk /usr/local/include/raylib.h > src/keys.rs
+
+# convert a raylib key name like LEFT_BRACKET to PascalCase. the left and right
+# modifiers are shortened to LShift, RControl, etc.
+function variant(name,    parts, n, i, out) {
+	if (name ~ /^(LEFT|RIGHT)_(SHIFT|CONTROL|ALT|SUPER)$/) {
+		sub(/_/, "", name)
+		sub(/^LEFT/, "L", name)
+		sub(/^RIGHT/, "R", name)
+		return substr(name, 1, 2) tolower(substr(name, 3))
+	}
+	n = split(name, parts, "_")
+	out = ""
+	for (i = 1; i <= n; i++)
+		out = out toupper(substr(parts[i], 1, 1)) tolower(substr(parts[i], 2))
+	return out
+}
+
+/^typedef enum/ {
+	count = 0
+}
+
+/^[ \t]*KEY_[A-Z0-9_]+[ \t]*=/ {
+	name = $1
+	sub(/^KEY_/, "", name)
+	if (name == "NULL")
+		next
+	doc = ""
+	if (match($0, /\/\/ Key: /))
+		doc = substr($0, RSTART + RLENGTH)
+	count++
+	names[count] = name
+	docs[count] = doc
+}
+
+/^} KeyboardKey;/ {
+	print "//! Keyboard keys, generated from raylib's KeyboardKey by"
+	print "//! scripts/generate_keys.awk. Do not edit by hand"
+	print ""
+	print "raylib_enum! {"
+	print "    /// A key on a US keyboard layout"
+	print "    pub enum Key {"
+	for (i = 1; i <= count; i++) {
+		if (docs[i] != "")
+			printf "        /// %s\n", docs[i]
+		printf "        %s = raylib_sys::KeyboardKey_KEY_%s,\n", variant(names[i]), names[i]
 	}
+	print "    }"
+	print "}"
 }
@@ -0,0 +1,191 @@
+//! Window configuration applied before and just after opening the window
+
+use std::ffi::{c_int, c_uint, CString};
+
+use raylib_sys::{
+    ConfigFlags_FLAG_MSAA_4X_HINT, ConfigFlags_FLAG_VSYNC_HINT,
+    ConfigFlags_FLAG_WINDOW_HIDDEN, ConfigFlags_FLAG_WINDOW_HIGHDPI,
+    ConfigFlags_FLAG_WINDOW_RESIZABLE, ConfigFlags_FLAG_WINDOW_TRANSPARENT,
+    ConfigFlags_FLAG_WINDOW_UNDECORATED, SetExitKey, SetTargetFPS,
+    SetWindowIcon, SetWindowMaxSize, SetWindowMinSize, SetWindowPosition,
+};
+
+use crate::{
+    image::Image,
+    keys::Key,
+    logging::{self, LogLevel},
+    DonkeyError, Window,
+};
+
+/// Builder for a [Window], obtained from [Window::builder].
+///
+/// ```notrust
+/// let win = Window::builder(800, 600, "title")
+///     .resizable()
+///     .msaa_4x()
+///     .target_fps(60)
+///     .build()?;
+/// ```
+pub struct WindowBuilder {
+    width: i32,
+    height: i32,
+    title: String,
+    flags: c_uint,
+    target_fps: Option<u32>,
+    min_size: Option<(i32, i32)>,
+    max_size: Option<(i32, i32)>,
+    position: Option<(i32, i32)>,
+    icon: Option<Image>,
+    exit_key: Option<Option<Key>>,
+    log_level: Option<LogLevel>,
+}
+
+impl WindowBuilder {
+    pub fn new(width: i32, height: i32, title: &str) -> Self {
+        Self {
+            width,
+            height,
+            title: title.to_owned(),
+            flags: 0,
+            target_fps: None,
+            min_size: None,
+            max_size: None,
+            position: None,
+            icon: None,
+            exit_key: None,
+            log_level: None,
+        }
+    }
+
+    fn flag(mut self, flag: c_uint) -> Self {
+        self.flags |= flag;
+        self
+    }
+
+    /// Allow the window to be resized by the user
+    pub fn resizable(self) -> Self {
+        self.flag(ConfigFlags_FLAG_WINDOW_RESIZABLE)
+    }
+
+    /// Try to enable 4x multisample anti-aliasing
+    pub fn msaa_4x(self) -> Self {
+        self.flag(ConfigFlags_FLAG_MSAA_4X_HINT)
+    }
+
+    /// Try to enable V-Sync on the GPU
+    pub fn vsync(self) -> Self {
+        self.flag(ConfigFlags_FLAG_VSYNC_HINT)
+    }
+
+    /// Open the window without decorations like the title bar and borders
+    pub fn undecorated(self) -> Self {
+        self.flag(ConfigFlags_FLAG_WINDOW_UNDECORATED)
+    }
+
+    /// Open the window hidden
+    pub fn hidden(self) -> Self {
+        self.flag(ConfigFlags_FLAG_WINDOW_HIDDEN)
+    }
+
+    /// Support high-DPI displays
+    pub fn high_dpi(self) -> Self {
+        self.flag(ConfigFlags_FLAG_WINDOW_HIGHDPI)
+    }
+
+    /// Allow a transparent framebuffer
+    pub fn transparent(self) -> Self {
+        self.flag(ConfigFlags_FLAG_WINDOW_TRANSPARENT)
+    }
+
+    /// Set the target frames per second. See [Window::set_target_fps]
+    pub fn target_fps(mut self, fps: u32) -> Self {
+        self.target_fps = Some(fps);
+        self
+    }
+
+    /// Set the minimum dimensions of a [WindowBuilder::resizable] window
+    pub fn min_size(mut self, width: i32, height: i32) -> Self {
+        self.min_size = Some((width, height));
+        self
+    }
+
+    /// Set the maximum dimensions of a [WindowBuilder::resizable] window
+    pub fn max_size(mut self, width: i32, height: i32) -> Self {
+        self.max_size = Some((width, height));
+        self
+    }
+
+    /// Set the initial position of the window on the screen
+    pub fn position(mut self, x: i32, y: i32) -> Self {
+        self.position = Some((x, y));
+        self
+    }
+
+    /// Set the window icon. `icon` must be in the R8G8B8A8 pixel format
+    pub fn icon(mut self, icon: Image) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    /// Set the key that closes the window, which is Escape by default. `None`
+    /// disables closing the window from the keyboard
+    pub fn exit_key(mut self, key: Option<Key>) -> Self {
+        self.exit_key = Some(key);
+        self
+    }
+
+    /// Set the minimum level of raylib's log messages, which are forwarded to
+    /// the [log] crate. raylib's default is [LogLevel::Info]
+    pub fn log_level(mut self, level: LogLevel) -> Self {
+        self.log_level = Some(level);
+        self
+    }
+
+    /// Disable raylib's log messages entirely
+    pub fn silence_log(self) -> Self {
+        self.log_level(LogLevel::None)
+    }
+
+    /// Open the window with the current settings
+    pub fn build(self) -> Result<Window, DonkeyError> {
+        let title = CString::new(self.title)?;
+        logging::init();
+        if let Some(level) = self.log_level {
+            logging::set_level(level);
+        }
+        let win = Window::open(self.width, self.height, &title, self.flags)?;
+        unsafe {
+            if let Some(fps) = self.target_fps {
+                SetTargetFPS(fps as c_int);
+            }
+            if let Some((width, height)) = self.min_size {
+                SetWindowMinSize(width, height);
+            }
+            if let Some((width, height)) = self.max_size {
+                SetWindowMaxSize(width, height);
+            }
+            if let Some((x, y)) = self.position {
+                SetWindowPosition(x, y);
+            }
+            if let Some(icon) = &self.icon {
+                SetWindowIcon(icon.inner);
+            }
+            if let Some(key) = self.exit_key {
+                SetExitKey(key.map_or(0, |key| key as c_int));
+            }
+        }
+        Ok(win)
+    }
+
+    /// Open the window and call `f` once per frame until it should close
+    pub fn run(
+        self,
+        mut f: impl FnMut(&mut Window),
+    ) -> Result<(), DonkeyError> {
+        let mut win = self.build()?;
+        while !win.should_close() {
+            f(&mut win);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,987 @@
+//! Camera controllers that move a [Camera3D] in response to user input. Each
+//! controller has its own speeds and [CameraBindings], so they can be tweaked
+//! freely:
+//!
+//! Speeds are per second and scaled by the frame time passed to
+//! [CameraController::update], so movement looks the same at any frame rate.
+//! Set `smoothing` to ease in and out of movement rather than starting and
+//! stopping instantly.
+//!
+//! ```notrust
+//! let mut controller = FirstPersonController {
+//!     move_speed: 10.0,
+//!     smoothing: 0.1,
+//!     ..Default::default()
+//! };
+//! while !win.should_close() {
+//!     let input = InputState::capture(&win);
+//!     controller.update(&mut camera, &input, win.get_frame_time());
+//!     ...
+//! }
+//! ```
+
+use raylib_sys::{
+    Quaternion, CAMERA_MOUSE_MOVE_SENSITIVITY, CAMERA_MOVE_SPEED,
+    CAMERA_ORBITAL_SPEED, CAMERA_PAN_SPEED, CAMERA_ROTATION_SPEED,
+};
+
+use crate::{
+    gamepad::GamepadAxis, input::InputState, keys::Key, mouse::MouseButton,
+    BoundingBox, Camera3D, CameraMode, CameraProjection, Vector3, Window,
+};
+
+/// Default move speed in units per second. raylib's `CAMERA_MOVE_SPEED` is
+/// per frame, so this is that speed at 60 frames per second
+pub const DEFAULT_MOVE_SPEED: f32 = CAMERA_MOVE_SPEED * 60.0;
+
+/// Default rotation speed in radians per second, raylib's per-frame
+/// `CAMERA_ROTATION_SPEED` at 60 frames per second
+pub const DEFAULT_ROTATION_SPEED: f32 = CAMERA_ROTATION_SPEED * 60.0;
+
+/// Default [FreeFlyController::pan_speed] in units per second, raylib's
+/// per-frame `CAMERA_PAN_SPEED` at 60 frames per second
+pub const DEFAULT_PAN_SPEED: f32 = CAMERA_PAN_SPEED * 60.0;
+
+/// Default gamepad look speed in radians per second at full stick. raylib
+/// turned by twice `CAMERA_MOUSE_MOVE_SENSITIVITY` per frame, so this is
+/// that at 60 frames per second
+pub const DEFAULT_GAMEPAD_SENSITIVITY: f32 =
+    2.0 * CAMERA_MOUSE_MOVE_SENSITIVITY * 60.0;
+
+/// Distance moved toward or away from the target by the zoom keys
+const ZOOM_STEP: f32 = 2.0;
+
+/// How far a gamepad stick must be pushed to move the camera
+const GAMEPAD_MOVE_THRESHOLD: f32 = 0.25;
+
+pub trait CameraController {
+    /// Move `camera` in response to `input`. `dt` is the time in seconds since
+    /// the last update
+    fn update(&mut self, camera: &mut Camera3D, input: &InputState, dt: f32);
+}
+
+/// The inputs used by a [CameraController]. Not every controller uses every
+/// binding
+#[derive(Clone, Debug)]
+pub struct CameraBindings {
+    pub forward: Key,
+    pub backward: Key,
+    pub left: Key,
+    pub right: Key,
+    pub up: Key,
+    pub down: Key,
+    pub pitch_up: Key,
+    pub pitch_down: Key,
+    pub yaw_left: Key,
+    pub yaw_right: Key,
+    pub roll_left: Key,
+    pub roll_right: Key,
+    pub zoom_in: Key,
+    pub zoom_out: Key,
+    /// Hold to rotate the view with the mouse
+    pub look_button: MouseButton,
+    /// Hold to pan the view with the mouse
+    pub pan_button: MouseButton,
+    pub move_x_axis: GamepadAxis,
+    pub move_y_axis: GamepadAxis,
+    pub look_x_axis: GamepadAxis,
+    pub look_y_axis: GamepadAxis,
+}
+
+impl Default for CameraBindings {
+    fn default() -> Self {
+        Self {
+            forward: Key::W,
+            backward: Key::S,
+            left: Key::A,
+            right: Key::D,
+            up: Key::Space,
+            down: Key::LControl,
+            pitch_up: Key::Up,
+            pitch_down: Key::Down,
+            yaw_left: Key::Left,
+            yaw_right: Key::Right,
+            roll_left: Key::Q,
+            roll_right: Key::E,
+            zoom_in: Key::KpAdd,
+            zoom_out: Key::KpSubtract,
+            look_button: MouseButton::Left,
+            pan_button: MouseButton::Middle,
+            move_x_axis: GamepadAxis::LeftX,
+            move_y_axis: GamepadAxis::LeftY,
+            look_x_axis: GamepadAxis::RightX,
+            look_y_axis: GamepadAxis::RightY,
+        }
+    }
+}
+
+/// How the shared movement code below should treat the camera
+#[derive(Clone, Copy)]
+struct Motion {
+    move_in_world_plane: bool,
+    rotate_around_target: bool,
+    lock_view: bool,
+}
+
+/// Camera velocity in its own frame of reference, in units per second for
+/// translation and radians per second for rotation
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct CameraVelocity {
+    pub right: f32,
+    pub up: f32,
+    pub forward: f32,
+    /// Positive turns left
+    pub yaw: f32,
+    /// Positive looks up
+    pub pitch: f32,
+    /// Positive rolls right
+    pub roll: f32,
+}
+
+impl CameraVelocity {
+    /// Move toward `target` with exponential damping. `smoothing` is the time
+    /// in seconds to cover about 63% of the difference; zero or less jumps
+    /// straight to `target`
+    pub fn approach(&mut self, target: Self, smoothing: f32, dt: f32) {
+        let t = if smoothing > 0.0 {
+            1.0 - (-dt / smoothing).exp()
+        } else {
+            1.0
+        };
+        self.right += (target.right - self.right) * t;
+        self.up += (target.up - self.up) * t;
+        self.forward += (target.forward - self.forward) * t;
+        self.yaw += (target.yaw - self.yaw) * t;
+        self.pitch += (target.pitch - self.pitch) * t;
+        self.roll += (target.roll - self.roll) * t;
+    }
+
+    /// Move and rotate `camera` by this velocity for `dt` seconds
+    fn apply(&self, camera: &mut Camera3D, dt: f32, m: Motion) {
+        camera.yaw(self.yaw * dt, m.rotate_around_target);
+        camera.pitch(
+            self.pitch * dt,
+            m.lock_view,
+            m.rotate_around_target,
+            false,
+        );
+        camera.roll(self.roll * dt);
+        camera.move_forward(self.forward * dt, m.move_in_world_plane);
+        camera.move_right(self.right * dt, m.move_in_world_plane);
+        camera.move_up(self.up * dt);
+    }
+}
+
+/// +1, -1 or 0 depending on which of the two keys are held
+fn key_axis(input: &InputState, negative: Key, positive: Key) -> f32 {
+    input.is_key_down(positive) as i32 as f32
+        - input.is_key_down(negative) as i32 as f32
+}
+
+/// Like [f32::signum], but zero for zero
+fn sign(x: f32) -> f32 {
+    (x > 0.0) as i32 as f32 - (x < 0.0) as i32 as f32
+}
+
+fn rotate_keys(
+    v: &mut CameraVelocity,
+    input: &InputState,
+    b: &CameraBindings,
+    speed: f32,
+) {
+    v.pitch += key_axis(input, b.pitch_down, b.pitch_up) * speed;
+    v.yaw += key_axis(input, b.yaw_right, b.yaw_left) * speed;
+    v.roll += key_axis(input, b.roll_left, b.roll_right) * speed;
+}
+
+/// The mouse reports a distance rather than a rate, so it is divided by `dt`
+/// here and multiplied back when the velocity is applied
+fn mouse_look(
+    v: &mut CameraVelocity,
+    input: &InputState,
+    b: &CameraBindings,
+    sensitivity: f32,
+    dt: f32,
+) {
+    if !input.is_mouse_button_down(b.look_button) {
+        return;
+    }
+    let delta = input.mouse_delta;
+    v.yaw -= delta.x * sensitivity / dt;
+    v.pitch -= delta.y * sensitivity / dt;
+}
+
+fn move_keys(
+    v: &mut CameraVelocity,
+    input: &InputState,
+    b: &CameraBindings,
+    speed: f32,
+) {
+    v.forward += key_axis(input, b.backward, b.forward) * speed;
+    v.right += key_axis(input, b.left, b.right) * speed;
+}
+
+/// While a gamepad is connected it replaces the mouse and movement keys, as
+/// it did in raylib's `UpdateCamera`
+fn gamepad(
+    v: &mut CameraVelocity,
+    input: &InputState,
+    b: &CameraBindings,
+    move_speed: f32,
+    sensitivity: f32,
+) {
+    v.yaw -= input.gamepad_axis(b.look_x_axis) * sensitivity;
+    v.pitch -= input.gamepad_axis(b.look_y_axis) * sensitivity;
+
+    let move_x = input.gamepad_axis(b.move_x_axis);
+    let move_y = input.gamepad_axis(b.move_y_axis);
+    if move_y <= -GAMEPAD_MOVE_THRESHOLD {
+        v.forward += move_speed;
+    }
+    if move_x <= -GAMEPAD_MOVE_THRESHOLD {
+        v.right -= move_speed;
+    }
+    if move_y >= GAMEPAD_MOVE_THRESHOLD {
+        v.forward -= move_speed;
+    }
+    if move_x >= GAMEPAD_MOVE_THRESHOLD {
+        v.right += move_speed;
+    }
+}
+
+fn zoom(camera: &mut Camera3D, input: &InputState, b: &CameraBindings) {
+    camera.move_to_target(-input.mouse_wheel);
+    if input.is_key_pressed(b.zoom_out) {
+        camera.move_to_target(ZOOM_STEP);
+    }
+    if input.is_key_pressed(b.zoom_in) {
+        camera.move_to_target(-ZOOM_STEP);
+    }
+}
+
+/// Fly freely in the direction the camera is facing, with vertical movement
+/// and mouse panning
+#[derive(Clone, Debug)]
+pub struct FreeFlyController {
+    /// Units per second
+    pub move_speed: f32,
+    /// Radians per second
+    pub rotation_speed: f32,
+    /// Units per second while the mouse is moving with the pan button held
+    pub pan_speed: f32,
+    /// Radians per pixel
+    pub mouse_sensitivity: f32,
+    /// Radians per second with the look stick pushed all the way
+    pub gamepad_sensitivity: f32,
+    /// Seconds to reach most of the target velocity. Zero disables smoothing
+    pub smoothing: f32,
+    pub bindings: CameraBindings,
+    pub velocity: CameraVelocity,
+}
+
+impl Default for FreeFlyController {
+    fn default() -> Self {
+        Self {
+            move_speed: DEFAULT_MOVE_SPEED,
+            rotation_speed: DEFAULT_ROTATION_SPEED,
+            pan_speed: DEFAULT_PAN_SPEED,
+            mouse_sensitivity: CAMERA_MOUSE_MOVE_SENSITIVITY,
+            gamepad_sensitivity: DEFAULT_GAMEPAD_SENSITIVITY,
+            smoothing: 0.0,
+            bindings: CameraBindings::default(),
+            velocity: CameraVelocity::default(),
+        }
+    }
+}
+
+impl CameraController for FreeFlyController {
+    fn update(&mut self, camera: &mut Camera3D, input: &InputState, dt: f32) {
+        const M: Motion = Motion {
+            move_in_world_plane: false,
+            rotate_around_target: false,
+            lock_view: false,
+        };
+        zoom(camera, input, &self.bindings);
+        if dt <= 0.0 {
+            return;
+        }
+        let b = &self.bindings;
+        let mut target = CameraVelocity::default();
+        rotate_keys(&mut target, input, b, self.rotation_speed);
+        if input.gamepad.is_some() {
+            gamepad(
+                &mut target,
+                input,
+                b,
+                self.move_speed,
+                self.gamepad_sensitivity,
+            );
+        } else {
+            if input.is_mouse_button_down(b.pan_button) {
+                let delta = input.mouse_delta;
+                target.right += sign(delta.x) * self.pan_speed;
+                target.up -= sign(delta.y) * self.pan_speed;
+            } else {
+                mouse_look(&mut target, input, b, self.mouse_sensitivity, dt);
+            }
+            move_keys(&mut target, input, b, self.move_speed);
+        }
+        target.up += key_axis(input, b.down, b.up) * self.move_speed;
+        self.velocity.approach(target, self.smoothing, dt);
+        self.velocity.apply(camera, dt, M);
+    }
+}
+
+/// Circle the target automatically, with zoom
+#[derive(Clone, Debug)]
+pub struct OrbitalController {
+    /// Rotation speed around the target in radians per second
+    pub orbit_speed: f32,
+    pub bindings: CameraBindings,
+}
+
+impl Default for OrbitalController {
+    fn default() -> Self {
+        Self {
+            orbit_speed: CAMERA_ORBITAL_SPEED,
+            bindings: CameraBindings::default(),
+        }
+    }
+}
+
+impl CameraController for OrbitalController {
+    fn update(&mut self, camera: &mut Camera3D, input: &InputState, dt: f32) {
+        let rotation =
+            Quaternion::from_axis_angle(camera.up(), self.orbit_speed * dt);
+        camera.position =
+            camera.target + rotation * (camera.position - camera.target);
+        zoom(camera, input, &self.bindings);
+    }
+}
+
+/// Walk along the ground plane and look around from the camera position
+#[derive(Clone, Debug)]
+pub struct FirstPersonController {
+    /// Units per second
+    pub move_speed: f32,
+    /// Radians per second
+    pub rotation_speed: f32,
+    /// Radians per pixel
+    pub mouse_sensitivity: f32,
+    /// Radians per second with the look stick pushed all the way
+    pub gamepad_sensitivity: f32,
+    /// Seconds to reach most of the target velocity. Zero disables smoothing
+    pub smoothing: f32,
+    pub bindings: CameraBindings,
+    pub velocity: CameraVelocity,
+}
+
+impl Default for FirstPersonController {
+    fn default() -> Self {
+        Self {
+            move_speed: DEFAULT_MOVE_SPEED,
+            rotation_speed: DEFAULT_ROTATION_SPEED,
+            mouse_sensitivity: CAMERA_MOUSE_MOVE_SENSITIVITY,
+            gamepad_sensitivity: DEFAULT_GAMEPAD_SENSITIVITY,
+            smoothing: 0.0,
+            bindings: CameraBindings::default(),
+            velocity: CameraVelocity::default(),
+        }
+    }
+}
+
+impl CameraController for FirstPersonController {
+    fn update(&mut self, camera: &mut Camera3D, input: &InputState, dt: f32) {
+        const M: Motion = Motion {
+            move_in_world_plane: true,
+            rotate_around_target: false,
+            lock_view: true,
+        };
+        if dt <= 0.0 {
+            return;
+        }
+        let b = &self.bindings;
+        let mut target = CameraVelocity::default();
+        rotate_keys(&mut target, input, b, self.rotation_speed);
+        if input.gamepad.is_some() {
+            gamepad(
+                &mut target,
+                input,
+                b,
+                self.move_speed,
+                self.gamepad_sensitivity,
+            );
+        } else {
+            mouse_look(&mut target, input, b, self.mouse_sensitivity, dt);
+            move_keys(&mut target, input, b, self.move_speed);
+        }
+        self.velocity.approach(target, self.smoothing, dt);
+        self.velocity.apply(camera, dt, M);
+    }
+}
+
+/// Walk along the ground plane and look around the target, with zoom
+#[derive(Clone, Debug)]
+pub struct ThirdPersonController {
+    /// Units per second
+    pub move_speed: f32,
+    /// Radians per second
+    pub rotation_speed: f32,
+    /// Radians per pixel
+    pub mouse_sensitivity: f32,
+    /// Radians per second with the look stick pushed all the way
+    pub gamepad_sensitivity: f32,
+    /// Seconds to reach most of the target velocity. Zero disables smoothing
+    pub smoothing: f32,
+    pub bindings: CameraBindings,
+    pub velocity: CameraVelocity,
+}
+
+impl Default for ThirdPersonController {
+    fn default() -> Self {
+        Self {
+            move_speed: DEFAULT_MOVE_SPEED,
+            rotation_speed: DEFAULT_ROTATION_SPEED,
+            mouse_sensitivity: CAMERA_MOUSE_MOVE_SENSITIVITY,
+            gamepad_sensitivity: DEFAULT_GAMEPAD_SENSITIVITY,
+            smoothing: 0.0,
+            bindings: CameraBindings::default(),
+            velocity: CameraVelocity::default(),
+        }
+    }
+}
+
+impl CameraController for ThirdPersonController {
+    fn update(&mut self, camera: &mut Camera3D, input: &InputState, dt: f32) {
+        const M: Motion = Motion {
+            move_in_world_plane: true,
+            rotate_around_target: true,
+            lock_view: true,
+        };
+        zoom(camera, input, &self.bindings);
+        if dt <= 0.0 {
+            return;
+        }
+        let b = &self.bindings;
+        let mut target = CameraVelocity::default();
+        rotate_keys(&mut target, input, b, self.rotation_speed);
+        if input.gamepad.is_some() {
+            gamepad(
+                &mut target,
+                input,
+                b,
+                self.move_speed,
+                self.gamepad_sensitivity,
+            );
+        } else {
+            mouse_look(&mut target, input, b, self.mouse_sensitivity, dt);
+            move_keys(&mut target, input, b, self.move_speed);
+        }
+        self.velocity.approach(target, self.smoothing, dt);
+        self.velocity.apply(camera, dt, M);
+    }
+}
+
+/// Inspect an object by dragging the view around the target like a trackball.
+/// Rotations are applied with quaternions, so the camera can turn over the top
+/// of the target without gimbal lock. Dragging with `pan_button` moves the
+/// target in the view plane, the mouse wheel zooms, and double-clicking
+/// recenters the view on the point under the cursor.
+#[derive(Clone, Debug)]
+pub struct ArcballController {
+    /// Radians per pixel dragged
+    pub rotation_sensitivity: f32,
+    /// Fraction of the distance to the target covered per wheel step
+    pub zoom_step: f32,
+    /// Closest the camera may get to the target, or the smallest view height
+    /// for an orthographic camera
+    pub min_distance: f32,
+    /// Most seconds between two clicks for them to count as a double-click
+    pub double_click_time: f32,
+    pub rotate_button: MouseButton,
+    pub pan_button: MouseButton,
+    /// Restores the view passed to [ArcballController::new]
+    pub reset_key: Option<Key>,
+    initial: Camera3D,
+    since_click: f32,
+}
+
+impl ArcballController {
+    /// An arcball controller that resets to `camera`
+    pub fn new(camera: &Camera3D) -> Self {
+        Self {
+            rotation_sensitivity: 0.01,
+            zoom_step: 0.1,
+            min_distance: 0.01,
+            double_click_time: 0.3,
+            rotate_button: MouseButton::Left,
+            pan_button: MouseButton::Right,
+            reset_key: Some(Key::R),
+            initial: *camera,
+            since_click: f32::INFINITY,
+        }
+    }
+
+    /// Restore the view passed to [ArcballController::new]
+    pub fn reset(&self, camera: &mut Camera3D) {
+        *camera = self.initial;
+    }
+
+    /// Move the camera without rotating it so that it looks at `point`
+    pub fn recenter(&self, camera: &mut Camera3D, point: Vector3) {
+        let offset = point - camera.target;
+        camera.position += offset;
+        camera.target = point;
+    }
+}
+
+/// The right and up directions of the view plane
+fn view_axes(camera: &Camera3D) -> (Vector3, Vector3) {
+    let forward = (camera.target - camera.position).normalize();
+    let right = forward.cross(camera.up).normalize();
+    (right, right.cross(forward))
+}
+
+/// Size of a pixel in world units on the plane through the target
+fn units_per_pixel(camera: &Camera3D, screen_height: f32) -> f32 {
+    if screen_height <= 0.0 {
+        return 0.0;
+    }
+    if camera.projection == CameraProjection::Orthographic as i32 {
+        camera.fovy / screen_height
+    } else {
+        let distance = (camera.position - camera.target).length();
+        let half_fovy = camera.fovy.to_radians() / 2.0;
+        2.0 * distance * half_fovy.tan() / screen_height
+    }
+}
+
+impl CameraController for ArcballController {
+    fn update(&mut self, camera: &mut Camera3D, input: &InputState, dt: f32) {
+        if self.reset_key.is_some_and(|key| input.is_key_pressed(key)) {
+            self.reset(camera);
+            return;
+        }
+
+        if input.mouse_wheel != 0.0 {
+            let factor = 1.0 - input.mouse_wheel * self.zoom_step;
+            if camera.projection == CameraProjection::Orthographic as i32 {
+                camera.fovy = (camera.fovy * factor).max(self.min_distance);
+            } else {
+                let offset = camera.position - camera.target;
+                let distance =
+                    (offset.length() * factor).max(self.min_distance);
+                camera.position =
+                    camera.target + offset.normalize().scale(distance);
+            }
+        }
+
+        let delta = input.mouse_delta;
+        let (right, up) = view_axes(camera);
+        if input.is_mouse_button_down(self.pan_button) {
+            let scale = units_per_pixel(camera, input.screen_size.y);
+            let offset =
+                right.scale(-delta.x * scale) + up.scale(delta.y * scale);
+            camera.position += offset;
+            camera.target += offset;
+        } else if input.is_mouse_button_down(self.rotate_button)
+            && (delta.x != 0.0 || delta.y != 0.0)
+        {
+            // dragging right turns the object right, so the camera goes left
+            let axis = up.scale(-delta.x) + right.scale(-delta.y);
+            let angle = delta.x.hypot(delta.y) * self.rotation_sensitivity;
+            let rotation = Quaternion::from_axis_angle(axis, angle);
+            let offset = camera.position - camera.target;
+            camera.position =
+                camera.target + offset.rotate_by_quaternion(rotation);
+            camera.up = camera.up.rotate_by_quaternion(rotation);
+        }
+
+        self.since_click += dt;
+        if input.is_mouse_button_pressed(self.rotate_button) {
+            if self.since_click <= self.double_click_time {
+                let scale = units_per_pixel(camera, input.screen_size.y);
+                let x = input.mouse_position.x - input.screen_size.x / 2.0;
+                let y = input.mouse_position.y - input.screen_size.y / 2.0;
+                let point = camera.target + right.scale(x * scale)
+                    - up.scale(y * scale);
+                self.recenter(camera, point);
+                self.since_click = f32::INFINITY;
+            } else {
+                self.since_click = 0.0;
+            }
+        }
+    }
+}
+
+/// Standard directions to look at a scene from, keeping the current target
+/// and distance
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ViewPreset {
+    /// Looking along -Z
+    Front,
+    /// Looking down along -Y, with -Z at the top of the screen
+    Top,
+    /// Looking along -X
+    Side,
+    /// Looking down diagonally from +X, +Y, +Z
+    Isometric,
+}
+
+impl ViewPreset {
+    /// Unit vector from the target to the camera, and the camera up vector
+    fn direction_and_up(self) -> (Vector3, Vector3) {
+        let y = Vector3::new(0.0, 1.0, 0.0);
+        match self {
+            Self::Front => (Vector3::new(0.0, 0.0, 1.0), y),
+            Self::Top => (y, Vector3::new(0.0, 0.0, -1.0)),
+            Self::Side => (Vector3::new(1.0, 0.0, 0.0), y),
+            Self::Isometric => (Vector3::new(1.0, 1.0, 1.0).normalize(), y),
+        }
+    }
+
+    /// `camera` moved to look at its target from this direction
+    pub fn pose(self, camera: &Camera3D) -> Camera3D {
+        let (direction, up) = self.direction_and_up();
+        let distance = (camera.position - camera.target).length();
+        Camera3D {
+            position: camera.target + direction.scale(distance),
+            up,
+            ..*camera
+        }
+    }
+
+    /// `camera` moved to look at `bounds` from this direction, filling a
+    /// viewport of width / height `aspect`
+    pub fn fit(
+        self,
+        camera: &Camera3D,
+        bounds: BoundingBox,
+        aspect: f32,
+    ) -> Camera3D {
+        let mut camera = self.pose(camera);
+        camera.fit_bounds(bounds, aspect);
+        camera
+    }
+}
+
+/// Animates a camera from one pose to another, swinging around the target
+/// rather than cutting through it. As a [CameraController] it ignores input,
+/// so it can stand in for the usual controller until [Self::is_finished]
+#[derive(Clone, Debug)]
+pub struct CameraTransition {
+    from: Camera3D,
+    to: Camera3D,
+    /// Seconds
+    pub duration: f32,
+    elapsed: f32,
+}
+
+impl CameraTransition {
+    pub fn new(from: Camera3D, to: Camera3D, duration: f32) -> Self {
+        Self { from, to, duration, elapsed: 0.0 }
+    }
+
+    /// A transition from `camera` to `preset`
+    pub fn to_preset(
+        camera: &Camera3D,
+        preset: ViewPreset,
+        duration: f32,
+    ) -> Self {
+        Self::new(*camera, preset.pose(camera), duration)
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+
+    /// The camera pose `t` of the way through the transition, eased in and
+    /// out. The projection can't be blended, so it switches to the target's
+    /// only once `t` reaches 1
+    pub fn at(&self, t: f32) -> Camera3D {
+        let t = t.clamp(0.0, 1.0);
+        if t == 0.0 {
+            return self.from;
+        }
+        let projection = if t >= 1.0 {
+            self.to.projection
+        } else {
+            self.from.projection
+        };
+        let t = t * t * (3.0 - 2.0 * t);
+        let (from, to) = (&self.from, &self.to);
+
+        let from_offset = from.position - from.target;
+        let to_offset = to.position - to.target;
+        let from_dir = from_offset.normalize();
+        let to_dir = to_offset.normalize();
+        let angle = from_dir.dot(to_dir).clamp(-1.0, 1.0).acos();
+        let mut axis = from_dir.cross(to_dir);
+        if axis.length() < 1e-6 {
+            // opposite directions have no unique axis, so swing over the top
+            axis = from_dir.cross(from.up);
+        }
+        if axis.length() < 1e-6 {
+            // or around any side when looking straight along `up`
+            axis = from_dir.perpendicular();
+        }
+        let rotation = Quaternion::from_axis_angle(axis, angle * t);
+        let distance = from_offset.length()
+            + (to_offset.length() - from_offset.length()) * t;
+
+        let target = from.target.lerp(to.target, t);
+        let direction = rotation * from_dir;
+        let up = (rotation * from.up).lerp(to.up, t);
+        Camera3D {
+            position: target + direction.scale(distance),
+            target,
+            up: up.normalize(),
+            fovy: from.fovy + (to.fovy - from.fovy) * t,
+            projection,
+        }
+    }
+}
+
+impl CameraController for CameraTransition {
+    fn update(&mut self, camera: &mut Camera3D, _input: &InputState, dt: f32) {
+        self.elapsed += dt;
+        *camera = if self.duration > 0.0 {
+            self.at(self.elapsed / self.duration)
+        } else {
+            self.to
+        };
+    }
+}
+
+impl Window {
+    /// I didn't like the default UpdateCamera from rcamera, so this is a
+    /// modification to require holding down the mouse button to rotate. This
+    /// uses the default settings of the [CameraController] for `mode`, without
+    /// smoothing; use the controllers directly to change them
+    pub fn update_camera(&self, camera: &mut Camera3D, mode: CameraMode) {
+        let input = InputState::capture(self);
+        let dt = self.get_frame_time();
+        match mode {
+            CameraMode::Custom => {}
+            CameraMode::Free => {
+                FreeFlyController::default().update(camera, &input, dt)
+            }
+            CameraMode::Orbital => {
+                OrbitalController::default().update(camera, &input, dt)
+            }
+            CameraMode::FirstPerson => {
+                FirstPersonController::default().update(camera, &input, dt)
+            }
+            CameraMode::ThirdPerson => {
+                ThirdPersonController::default().update(camera, &input, dt)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{input::GamepadState, Vector2};
+
+    fn forward(speed: f32) -> CameraVelocity {
+        CameraVelocity {
+            forward: speed,
+            ..Default::default()
+        }
+    }
+
+    fn close(a: Vector3, b: Vector3) -> bool {
+        (a - b).length() < 1e-4
+    }
+
+    fn arcball_camera() -> Camera3D {
+        Camera3D::new(
+            Vector3::new(0.0, 0.0, 5.0),
+            Vector3::new(0.0, 0.0, 0.0),
+            Vector3::new(0.0, 1.0, 0.0),
+            45.0,
+            CameraProjection::Perspective,
+        )
+    }
+
+    #[test]
+    fn arcball_over_the_top() {
+        let mut camera = arcball_camera();
+        let mut arcball = ArcballController::new(&camera);
+        let mut input = InputState::default();
+        input.mouse_buttons_down.insert(MouseButton::Left);
+        let pixels = std::f32::consts::FRAC_PI_2 / arcball.rotation_sensitivity;
+        input.mouse_delta = Vector2 { x: 0.0, y: pixels };
+
+        arcball.update(&mut camera, &input, 0.1);
+        assert!(close(camera.position, Vector3::new(0.0, 5.0, 0.0)));
+        arcball.update(&mut camera, &input, 0.1);
+        assert!(close(camera.position, Vector3::new(0.0, 0.0, -5.0)));
+        assert!(close(camera.up, Vector3::new(0.0, -1.0, 0.0)));
+
+        input = InputState::default();
+        input.keys_pressed.insert(Key::R);
+        arcball.update(&mut camera, &input, 0.1);
+        assert!(close(camera.position, Vector3::new(0.0, 0.0, 5.0)));
+    }
+
+    #[test]
+    fn arcball_double_click() {
+        let mut camera = arcball_camera();
+        let mut arcball = ArcballController::new(&camera);
+        let mut input = InputState {
+            screen_size: Vector2 { x: 800.0, y: 600.0 },
+            mouse_position: Vector2 { x: 500.0, y: 300.0 },
+            ..Default::default()
+        };
+        input.mouse_buttons_pressed.insert(MouseButton::Left);
+
+        arcball.update(&mut camera, &input, 0.1);
+        assert!(close(camera.target, Vector3::new(0.0, 0.0, 0.0)));
+        arcball.update(&mut camera, &input, 0.1);
+        assert!(camera.target.x > 0.0);
+        assert!(close(
+            camera.position - camera.target,
+            Vector3::new(0.0, 0.0, 5.0)
+        ));
+    }
+
+    #[test]
+    fn presets_keep_distance() {
+        let camera = arcball_camera();
+        for preset in [ViewPreset::Top, ViewPreset::Side, ViewPreset::Isometric]
+        {
+            let pose = preset.pose(&camera);
+            assert!((pose.position.length() - 5.0).abs() < 1e-5);
+            assert_eq!(pose.target.length(), 0.0);
+        }
+        let top = ViewPreset::Top.pose(&camera);
+        assert!(close(top.position, Vector3::new(0.0, 5.0, 0.0)));
+    }
+
+    #[test]
+    fn transition() {
+        let camera = arcball_camera();
+        let mut transition =
+            CameraTransition::to_preset(&camera, ViewPreset::Side, 1.0);
+        let halfway = transition.at(0.5);
+        assert!((halfway.position.length() - 5.0).abs() < 1e-4);
+        assert!(close(halfway.up, Vector3::new(0.0, 1.0, 0.0)));
+
+        let mut got = camera;
+        let input = InputState::default();
+        transition.update(&mut got, &input, 0.5);
+        assert!(!transition.is_finished());
+        transition.update(&mut got, &input, 0.5);
+        assert!(transition.is_finished());
+        assert!(close(got.position, Vector3::new(5.0, 0.0, 0.0)));
+
+        // opposite directions still swing around rather than through
+        let back = Camera3D {
+            position: Vector3::new(0.0, 0.0, -5.0),
+            ..camera
+        };
+        let halfway = CameraTransition::new(camera, back, 1.0).at(0.5);
+        assert!((halfway.position.length() - 5.0).abs() < 1e-4);
+
+        // including when looking along `up`, with no side to swing over
+        let above = Camera3D {
+            position: Vector3::new(0.0, 5.0, 0.0),
+            ..camera
+        };
+        let below = Camera3D {
+            position: Vector3::new(0.0, -5.0, 0.0),
+            ..camera
+        };
+        let halfway = CameraTransition::new(above, below, 1.0).at(0.5);
+        assert!((halfway.position.length() - 5.0).abs() < 1e-4);
+        assert!(halfway.position.y.abs() < 1e-4);
+
+        // the start is exact, and the projection only changes at the end
+        let ortho = Camera3D {
+            fovy: 10.0,
+            projection: CameraProjection::Orthographic as i32,
+            ..back
+        };
+        let transition = CameraTransition::new(camera, ortho, 1.0);
+        let start = transition.at(0.0);
+        assert_eq!(start.position, camera.position);
+        assert_eq!(start.target, camera.target);
+        assert_eq!(start.up, camera.up);
+        assert_eq!(start.fovy, camera.fovy);
+        assert_eq!(start.projection, camera.projection);
+        assert_eq!(transition.at(0.99).projection, camera.projection);
+        assert_eq!(transition.at(1.0).projection, ortho.projection);
+    }
+
+    #[test]
+    fn gamepad_replaces_keys_and_mouse() {
+        let start = arcball_camera();
+        let mut input = InputState::default();
+        input.keys_down.insert(Key::W);
+        input.mouse_buttons_down.insert(MouseButton::Left);
+        input.mouse_delta = Vector2::new(10.0, 0.0);
+
+        let mut camera = start;
+        FirstPersonController::default().update(&mut camera, &input, 0.1);
+        assert!(!close(camera.position, start.position));
+
+        input.gamepad = Some(GamepadState::default());
+        let mut camera = start;
+        FirstPersonController::default().update(&mut camera, &input, 0.1);
+        assert!(close(camera.position, start.position));
+        assert!(close(camera.target, start.target));
+    }
+
+    #[test]
+    fn gamepad_look_speed() {
+        let mut input = InputState::default();
+        let mut pad = GamepadState::default();
+        pad.axes.insert(GamepadAxis::RightX, 1.0);
+        input.gamepad = Some(pad);
+        let controller = FirstPersonController::default();
+        let mut v = CameraVelocity::default();
+        gamepad(
+            &mut v,
+            &input,
+            &controller.bindings,
+            controller.move_speed,
+            controller.gamepad_sensitivity,
+        );
+        // update_camera turned 2 * CAMERA_MOUSE_MOVE_SENSITIVITY per frame
+        let per_frame = -2.0 * CAMERA_MOUSE_MOVE_SENSITIVITY;
+        assert!((v.yaw / 60.0 - per_frame).abs() < 1e-7);
+        assert_eq!(
+            FreeFlyController::default().gamepad_sensitivity,
+            controller.gamepad_sensitivity
+        );
+    }
+
+    #[test]
+    fn no_smoothing() {
+        let mut v = CameraVelocity::default();
+        v.approach(forward(5.0), 0.0, 1.0 / 60.0);
+        assert_eq!(v, forward(5.0));
+    }
+
+    #[test]
+    fn smoothing_is_frame_rate_independent() {
+        let mut slow = CameraVelocity::default();
+        slow.approach(forward(5.0), 0.2, 1.0 / 30.0);
+        let mut fast = CameraVelocity::default();
+        fast.approach(forward(5.0), 0.2, 1.0 / 60.0);
+        fast.approach(forward(5.0), 0.2, 1.0 / 60.0);
+        assert!((slow.forward - fast.forward).abs() < 1e-5);
+        assert!(slow.forward > 0.0 && slow.forward < 5.0);
+    }
+
+    #[test]
+    fn smoothing_settles() {
+        let mut v = forward(5.0);
+        for _ in 0..600 {
+            v.approach(CameraVelocity::default(), 0.1, 1.0 / 60.0);
+        }
+        assert!(v.forward.abs() < 1e-3);
+    }
+}
@@ -0,0 +1,169 @@
+//! Drawing scopes. [Window::draw] begins a frame and returns a [DrawHandle]
+//! that ends it when dropped, so every `BeginDrawing` is paired with an
+//! `EndDrawing`. 2D drawing with a camera happens inside [DrawHandle::mode2d],
+//! and 3D drawing inside [DrawHandle::mode3d].
+
+use std::{
+    ffi::{c_int, CString},
+    ops::Deref,
+};
+
+use raylib_sys::{
+    BeginDrawing, BeginMode2D, BeginMode3D, ClearBackground, DrawText,
+    EndDrawing, EndMode2D, EndMode3D,
+};
+
+use crate::{colors::IntoColor, Camera2D, Camera3D, DonkeyError, Window};
+
+/// A single frame of drawing, obtained from [Window::draw]. The frame is ended
+/// when the handle is dropped.
+///
+/// ```notrust
+/// let mut canvas = win.draw();
+/// canvas.clear_background(color);
+/// canvas.mode3d(&camera, |c3d| {
+///     c3d.draw_cube(...);
+/// });
+/// ```
+pub struct DrawHandle<'a> {
+    win: &'a mut Window,
+}
+
+impl Window {
+    /// Begin drawing a new frame
+    pub fn draw(&mut self) -> DrawHandle<'_> {
+        unsafe { BeginDrawing() }
+        DrawHandle { win: self }
+    }
+}
+
+impl Drop for DrawHandle<'_> {
+    fn drop(&mut self) {
+        unsafe { EndDrawing() }
+    }
+}
+
+impl Deref for DrawHandle<'_> {
+    type Target = Window;
+
+    fn deref(&self) -> &Self::Target {
+        self.win
+    }
+}
+
+impl DrawHandle<'_> {
+    pub fn clear_background(&self, color: impl IntoColor) {
+        unsafe { ClearBackground(color.into()) }
+    }
+
+    /// Draw `text` with the default font. Panics if `text` contains a NUL
+    /// byte; see [DrawHandle::try_draw_text]
+    pub fn draw_text(
+        &self,
+        text: impl Into<Vec<u8>>,
+        x: i32,
+        y: i32,
+        font_size: usize,
+        color: impl IntoColor,
+    ) {
+        self.try_draw_text(text, x, y, font_size, color).unwrap()
+    }
+
+    pub fn try_draw_text(
+        &self,
+        text: impl Into<Vec<u8>>,
+        x: i32,
+        y: i32,
+        font_size: usize,
+        color: impl IntoColor,
+    ) -> Result<(), DonkeyError> {
+        let cstr = CString::new(text)?;
+        unsafe {
+            DrawText(cstr.as_ptr(), x, y, font_size as c_int, color.into());
+        }
+        Ok(())
+    }
+
+    #[deprecated(note = "use `draw_text`, which takes `i32` coordinates")]
+    pub fn draw_text_usize(
+        &self,
+        text: impl Into<Vec<u8>>,
+        x: usize,
+        y: usize,
+        font_size: usize,
+        color: impl IntoColor,
+    ) {
+        self.draw_text(text, x as c_int, y as c_int, font_size, color)
+    }
+
+    #[deprecated(note = "use `try_draw_text`, which takes `i32` coordinates")]
+    pub fn try_draw_text_usize(
+        &self,
+        text: impl Into<Vec<u8>>,
+        x: usize,
+        y: usize,
+        font_size: usize,
+        color: impl IntoColor,
+    ) -> Result<(), DonkeyError> {
+        self.try_draw_text(text, x as c_int, y as c_int, font_size, color)
+    }
+
+    /// Draw in 2D with `camera` for the duration of `f`. The usual 2D drawing
+    /// methods are available through the scope, in world coordinates
+    pub fn mode2d<'a>(
+        &'a mut self,
+        camera: &Camera2D,
+        f: impl FnOnce(&mut Mode2D<'_, 'a>),
+    ) {
+        unsafe { BeginMode2D(*camera) }
+        let mut mode = Mode2D { canvas: self };
+        f(&mut mode);
+    }
+
+    /// Draw in 3D with `camera` for the duration of `f`
+    pub fn mode3d(&mut self, camera: &Camera3D, f: impl FnOnce(&mut Mode3D)) {
+        unsafe { BeginMode3D(*camera) }
+        let mut mode = Mode3D { win: self.win };
+        f(&mut mode);
+    }
+}
+
+/// A 2D camera scope within a frame, obtained from [DrawHandle::mode2d]. 2D
+/// mode is ended when the scope is dropped.
+pub struct Mode2D<'a, 'b> {
+    canvas: &'a DrawHandle<'b>,
+}
+
+impl Drop for Mode2D<'_, '_> {
+    fn drop(&mut self) {
+        unsafe { EndMode2D() }
+    }
+}
+
+impl<'b> Deref for Mode2D<'_, 'b> {
+    type Target = DrawHandle<'b>;
+
+    fn deref(&self) -> &Self::Target {
+        self.canvas
+    }
+}
+
+/// A 3D drawing scope within a frame, obtained from [DrawHandle::mode3d]. 3D
+/// mode is ended when the scope is dropped.
+pub struct Mode3D<'a> {
+    win: &'a Window,
+}
+
+impl Drop for Mode3D<'_> {
+    fn drop(&mut self) {
+        unsafe { EndMode3D() }
+    }
+}
+
+impl Deref for Mode3D<'_> {
+    type Target = Window;
+
+    fn deref(&self) -> &Self::Target {
+        self.win
+    }
+}
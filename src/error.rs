@@ -0,0 +1,134 @@
+use std::{error::Error, ffi::NulError, fmt::Display, io};
+
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum DonkeyError {
+    /// A string passed to raylib contained a NUL byte at `position`
+    InteriorNul { position: usize, source: NulError },
+    /// The named file does not exist
+    FileNotFound(String),
+    /// The named file exists but raylib could not decode it
+    UnsupportedFormat(String),
+    /// Creating a GPU resource, such as a texture or shader, failed
+    ResourceCreation(&'static str),
+    /// Another [crate::Window] is already open in this process
+    WindowAlreadyOpen,
+    /// raylib failed to create the window or its OpenGL context
+    WindowInit,
+    /// The audio device could not be initialized
+    AudioDeviceUnavailable,
+    /// `value` does not correspond to any variant of the raylib enum `kind`
+    InvalidValue { kind: &'static str, value: i32 },
+    /// `name` is not the name of any variant of the enum `kind`
+    UnknownName { kind: &'static str, name: String },
+    /// raylib rejected the gamepad mappings
+    GamepadMappings,
+    /// Reading or writing a file failed
+    Io(io::Error),
+    /// A config file could not be parsed
+    ConfigParse(toml::de::Error),
+    /// A config could not be serialized
+    ConfigWrite(toml::ser::Error),
+}
+
+impl Error for DonkeyError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            DonkeyError::InteriorNul { source, .. } => Some(source),
+            DonkeyError::Io(source) => Some(source),
+            DonkeyError::ConfigParse(source) => Some(source),
+            DonkeyError::ConfigWrite(source) => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl Display for DonkeyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DonkeyError::InteriorNul { position, .. } => {
+                write!(f, "string contains a NUL byte at position {position}")
+            }
+            DonkeyError::FileNotFound(name) => {
+                write!(f, "file not found: {name}")
+            }
+            DonkeyError::UnsupportedFormat(name) => {
+                write!(f, "unsupported file format: {name}")
+            }
+            DonkeyError::ResourceCreation(what) => {
+                write!(f, "failed to create GPU resource: {what}")
+            }
+            DonkeyError::WindowAlreadyOpen => {
+                write!(f, "a window is already open")
+            }
+            DonkeyError::WindowInit => {
+                write!(f, "failed to initialize window")
+            }
+            DonkeyError::AudioDeviceUnavailable => {
+                write!(f, "audio device unavailable")
+            }
+            DonkeyError::InvalidValue { kind, value } => {
+                write!(f, "invalid {kind} value: {value}")
+            }
+            DonkeyError::UnknownName { kind, name } => {
+                write!(f, "unknown {kind} name: {name:?}")
+            }
+            DonkeyError::GamepadMappings => {
+                write!(f, "failed to set gamepad mappings")
+            }
+            DonkeyError::Io(source) => write!(f, "I/O error: {source}"),
+            DonkeyError::ConfigParse(_) => write!(f, "failed to parse config"),
+            DonkeyError::ConfigWrite(_) => write!(f, "failed to write config"),
+        }
+    }
+}
+
+impl From<NulError> for DonkeyError {
+    fn from(value: NulError) -> Self {
+        Self::InteriorNul {
+            position: value.nul_position(),
+            source: value,
+        }
+    }
+}
+
+impl From<io::Error> for DonkeyError {
+    fn from(value: io::Error) -> Self {
+        Self::Io(value)
+    }
+}
+
+impl From<toml::de::Error> for DonkeyError {
+    fn from(value: toml::de::Error) -> Self {
+        Self::ConfigParse(value)
+    }
+}
+
+impl From<toml::ser::Error> for DonkeyError {
+    fn from(value: toml::ser::Error) -> Self {
+        Self::ConfigWrite(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::CString;
+
+    use super::*;
+
+    #[test]
+    fn interior_nul() {
+        let err: DonkeyError = CString::new("abc\0def").unwrap_err().into();
+        assert!(matches!(err, DonkeyError::InteriorNul { position: 3, .. }));
+        assert!(err.source().is_some());
+        assert_eq!(err.to_string(), "string contains a NUL byte at position 3");
+    }
+
+    #[test]
+    fn io() {
+        let err: DonkeyError =
+            io::Error::new(io::ErrorKind::NotFound, "no such file").into();
+        assert!(err.source().is_some());
+        assert_eq!(err.to_string(), "I/O error: no such file");
+    }
+}
@@ -0,0 +1,237 @@
+//! Gamepad input for up to [MAX_GAMEPADS] controllers
+
+use std::{
+    ffi::{c_int, CStr, CString},
+    path::Path,
+};
+
+use raylib_sys::{
+    GetGamepadAxisCount, GetGamepadAxisMovement, GetGamepadName,
+    IsGamepadAvailable, IsGamepadButtonDown, IsGamepadButtonPressed,
+    IsGamepadButtonReleased, IsGamepadButtonUp, SetGamepadMappings,
+};
+
+use crate::{DonkeyError, Vector2, Window};
+
+/// The number of gamepads raylib tracks
+pub const MAX_GAMEPADS: i32 = 4;
+
+/// The default radius below which stick input is ignored
+pub const DEFAULT_DEADZONE: f32 = 0.1;
+
+raylib_enum! {
+    /// Gamepad buttons, named by position to be independent of the labels on
+    /// a particular controller
+    pub enum GamepadButton {
+        /// D-pad up
+        LeftFaceUp = raylib_sys::GamepadButton_GAMEPAD_BUTTON_LEFT_FACE_UP,
+        /// D-pad right
+        LeftFaceRight =
+            raylib_sys::GamepadButton_GAMEPAD_BUTTON_LEFT_FACE_RIGHT,
+        /// D-pad down
+        LeftFaceDown = raylib_sys::GamepadButton_GAMEPAD_BUTTON_LEFT_FACE_DOWN,
+        /// D-pad left
+        LeftFaceLeft = raylib_sys::GamepadButton_GAMEPAD_BUTTON_LEFT_FACE_LEFT,
+        /// Y on Xbox, triangle on PlayStation
+        RightFaceUp = raylib_sys::GamepadButton_GAMEPAD_BUTTON_RIGHT_FACE_UP,
+        /// B on Xbox, circle on PlayStation
+        RightFaceRight =
+            raylib_sys::GamepadButton_GAMEPAD_BUTTON_RIGHT_FACE_RIGHT,
+        /// A on Xbox, cross on PlayStation
+        RightFaceDown =
+            raylib_sys::GamepadButton_GAMEPAD_BUTTON_RIGHT_FACE_DOWN,
+        /// X on Xbox, square on PlayStation
+        RightFaceLeft =
+            raylib_sys::GamepadButton_GAMEPAD_BUTTON_RIGHT_FACE_LEFT,
+        /// Top left shoulder button
+        LeftTrigger1 = raylib_sys::GamepadButton_GAMEPAD_BUTTON_LEFT_TRIGGER_1,
+        /// Bottom left shoulder button
+        LeftTrigger2 = raylib_sys::GamepadButton_GAMEPAD_BUTTON_LEFT_TRIGGER_2,
+        /// Top right shoulder button
+        RightTrigger1 =
+            raylib_sys::GamepadButton_GAMEPAD_BUTTON_RIGHT_TRIGGER_1,
+        /// Bottom right shoulder button
+        RightTrigger2 =
+            raylib_sys::GamepadButton_GAMEPAD_BUTTON_RIGHT_TRIGGER_2,
+        /// Select or back
+        MiddleLeft = raylib_sys::GamepadButton_GAMEPAD_BUTTON_MIDDLE_LEFT,
+        /// The PS or Xbox button
+        Middle = raylib_sys::GamepadButton_GAMEPAD_BUTTON_MIDDLE,
+        /// Start
+        MiddleRight = raylib_sys::GamepadButton_GAMEPAD_BUTTON_MIDDLE_RIGHT,
+        /// Pressing in the left stick
+        LeftThumb = raylib_sys::GamepadButton_GAMEPAD_BUTTON_LEFT_THUMB,
+        /// Pressing in the right stick
+        RightThumb = raylib_sys::GamepadButton_GAMEPAD_BUTTON_RIGHT_THUMB,
+    }
+}
+
+raylib_enum! {
+    pub enum GamepadAxis {
+        LeftX = raylib_sys::GamepadAxis_GAMEPAD_AXIS_LEFT_X,
+        LeftY = raylib_sys::GamepadAxis_GAMEPAD_AXIS_LEFT_Y,
+        RightX = raylib_sys::GamepadAxis_GAMEPAD_AXIS_RIGHT_X,
+        RightY = raylib_sys::GamepadAxis_GAMEPAD_AXIS_RIGHT_Y,
+        /// Analog left trigger, from -1 when released to 1 when fully pressed
+        LeftTrigger = raylib_sys::GamepadAxis_GAMEPAD_AXIS_LEFT_TRIGGER,
+        /// Analog right trigger, from -1 when released to 1 when fully pressed
+        RightTrigger = raylib_sys::GamepadAxis_GAMEPAD_AXIS_RIGHT_TRIGGER,
+    }
+}
+
+/// A connected gamepad, obtained from [Window::gamepads] or
+/// [Window::gamepad]
+#[derive(Clone, Copy)]
+pub struct Gamepad<'a> {
+    id: i32,
+    deadzone: f32,
+    _win: &'a Window,
+}
+
+impl Window {
+    /// The gamepad in slot `id`, if one is connected. Slots outside
+    /// `0..MAX_GAMEPADS` never have one
+    pub fn gamepad(&self, id: i32) -> Option<Gamepad<'_>> {
+        if !(0..MAX_GAMEPADS).contains(&id) {
+            return None;
+        }
+        if unsafe { IsGamepadAvailable(id) } {
+            Some(Gamepad {
+                id,
+                deadzone: DEFAULT_DEADZONE,
+                _win: self,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// All of the connected gamepads, in slot order
+    pub fn gamepads(&self) -> impl Iterator<Item = Gamepad<'_>> {
+        (0..MAX_GAMEPADS).filter_map(|id| self.gamepad(id))
+    }
+
+    /// Load SDL_GameControllerDB-style `mappings` for controllers that are not
+    /// recognized by default
+    pub fn set_gamepad_mappings(
+        &self,
+        mappings: &str,
+    ) -> Result<(), DonkeyError> {
+        let s = CString::new(mappings)?;
+        if unsafe { SetGamepadMappings(s.as_ptr()) } == 0 {
+            return Err(DonkeyError::GamepadMappings);
+        }
+        Ok(())
+    }
+
+    /// Load gamepad mappings from a file like `gamecontrollerdb.txt`. See
+    /// [Window::set_gamepad_mappings]
+    pub fn load_gamepad_mappings(
+        &self,
+        path: impl AsRef<Path>,
+    ) -> Result<(), DonkeyError> {
+        let mappings = std::fs::read_to_string(path)?;
+        self.set_gamepad_mappings(&mappings)
+    }
+}
+
+impl Gamepad<'_> {
+    /// The slot this gamepad occupies, from 0 to [MAX_GAMEPADS] - 1
+    pub fn id(&self) -> i32 {
+        self.id
+    }
+
+    /// The name reported by the controller's driver
+    pub fn name(&self) -> String {
+        unsafe {
+            let name = GetGamepadName(self.id);
+            if name.is_null() {
+                return String::new();
+            }
+            CStr::from_ptr(name).to_string_lossy().into_owned()
+        }
+    }
+
+    /// Set the radius below which [Gamepad::left_stick] and
+    /// [Gamepad::right_stick] report no movement
+    pub fn with_deadzone(mut self, deadzone: f32) -> Self {
+        self.deadzone = deadzone;
+        self
+    }
+
+    /// Check if a button is being pressed
+    pub fn is_button_down(&self, button: GamepadButton) -> bool {
+        unsafe { IsGamepadButtonDown(self.id, button as c_int) }
+    }
+
+    /// Check if a button has been pressed once
+    pub fn is_button_pressed(&self, button: GamepadButton) -> bool {
+        unsafe { IsGamepadButtonPressed(self.id, button as c_int) }
+    }
+
+    /// Check if a button has been released once
+    pub fn is_button_released(&self, button: GamepadButton) -> bool {
+        unsafe { IsGamepadButtonReleased(self.id, button as c_int) }
+    }
+
+    /// Check if a button is not being pressed
+    pub fn is_button_up(&self, button: GamepadButton) -> bool {
+        unsafe { IsGamepadButtonUp(self.id, button as c_int) }
+    }
+
+    /// The number of axes reported by the controller
+    pub fn axis_count(&self) -> i32 {
+        unsafe { GetGamepadAxisCount(self.id) }
+    }
+
+    /// The raw movement of `axis`, without any deadzone applied
+    pub fn axis(&self, axis: GamepadAxis) -> f32 {
+        unsafe { GetGamepadAxisMovement(self.id, axis as c_int) }
+    }
+
+    /// The position of the left stick, with the deadzone applied
+    pub fn left_stick(&self) -> Vector2 {
+        let x = self.axis(GamepadAxis::LeftX);
+        let y = self.axis(GamepadAxis::LeftY);
+        apply_deadzone(Vector2 { x, y }, self.deadzone)
+    }
+
+    /// The position of the right stick, with the deadzone applied
+    pub fn right_stick(&self) -> Vector2 {
+        let x = self.axis(GamepadAxis::RightX);
+        let y = self.axis(GamepadAxis::RightY);
+        apply_deadzone(Vector2 { x, y }, self.deadzone)
+    }
+}
+
+/// Zero out `stick` inside a circle of radius `deadzone` and rescale the rest
+/// of the range so that movement starts smoothly from zero at its edge
+pub(crate) fn apply_deadzone(stick: Vector2, deadzone: f32) -> Vector2 {
+    let len = (stick.x * stick.x + stick.y * stick.y).sqrt();
+    if len <= deadzone {
+        return Vector2 { x: 0.0, y: 0.0 };
+    }
+    let scale = ((len - deadzone) / (1.0 - deadzone)).min(1.0) / len;
+    Vector2 {
+        x: stick.x * scale,
+        y: stick.y * scale,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deadzone() {
+        let got = apply_deadzone(Vector2 { x: 0.05, y: -0.05 }, 0.1);
+        assert_eq!((got.x, got.y), (0.0, 0.0));
+
+        let got = apply_deadzone(Vector2 { x: 1.0, y: 0.0 }, 0.1);
+        assert_eq!((got.x, got.y), (1.0, 0.0));
+
+        let got = apply_deadzone(Vector2 { x: 0.0, y: -0.55 }, 0.1);
+        assert!(got.x.abs() < 1e-6);
+        assert!((got.y + 0.5).abs() < 1e-6);
+    }
+}
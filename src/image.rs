@@ -1,11 +1,11 @@
 use std::ffi::CString;
 
-use raylib_sys::{LoadImage, UnloadImage};
+use raylib_sys::{FileExists, LoadImage, UnloadImage};
 
-use crate::DonkeyError;
+use crate::{logging, DonkeyError};
 
 pub struct Image {
-    inner: raylib_sys::Image,
+    pub(crate) inner: raylib_sys::Image,
 }
 
 impl Drop for Image {
@@ -18,11 +18,17 @@ impl Drop for Image {
 
 impl Image {
     pub fn load(filename: impl Into<Vec<u8>>) -> Result<Self, DonkeyError> {
+        logging::init();
         unsafe {
             let s = CString::new(filename)?;
+            if !FileExists(s.as_ptr()) {
+                let name = s.to_string_lossy().into_owned();
+                return Err(DonkeyError::FileNotFound(name));
+            }
             let ret = LoadImage(s.as_ptr());
             if ret.data.is_null() {
-                return Err(DonkeyError("failed to load image file"));
+                let name = s.to_string_lossy().into_owned();
+                return Err(DonkeyError::UnsupportedFormat(name));
             }
             Ok(Self { inner: ret })
         }
@@ -34,9 +40,8 @@ mod tests {
     use super::*;
 
     #[test]
-    #[should_panic(expected = "failed to load image file")]
     fn test_load_image() {
-        let got = Image::load("fake_file").unwrap();
-        dbg!(got.inner.data.is_null());
+        let got = Image::load("fake_file");
+        assert!(matches!(got, Err(DonkeyError::FileNotFound(_))));
     }
 }
@@ -0,0 +1,586 @@
+//! Named input actions and axes bound to any mix of keys, mouse buttons, and
+//! gamepad buttons or axes, so that controls can be rebound by players.
+//!
+//! ```notrust
+//! let mut actions = Actions::new();
+//! actions.bind("jump", Binding::Key(Key::Space));
+//! actions.bind("jump", "pad0:RightFaceDown".parse()?);
+//! actions.bind_axis(
+//!     "move_x",
+//!     AxisBinding::Buttons {
+//!         negative: Binding::Key(Key::A),
+//!         positive: Binding::Key(Key::D),
+//!     },
+//! );
+//! while !win.should_close() {
+//!     actions.update(&win);
+//!     if actions.pressed("jump") { ... }
+//!     player.x += actions.axis("move_x") * speed * dt;
+//! }
+//! ```
+//!
+//! Bindings are saved to and loaded from TOML files like this:
+//!
+//! ```toml
+//! [actions]
+//! jump = ["key:Space", "pad0:RightFaceDown"]
+//! fire = ["mouse:Left"]
+//!
+//! [axes]
+//! move_x = [{ negative = "key:A", positive = "key:D" }, "pad0:LeftX"]
+//! ```
+
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    fmt::Display,
+    path::Path,
+    str::FromStr,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    gamepad::{
+        apply_deadzone, GamepadAxis, GamepadButton, DEFAULT_DEADZONE,
+        MAX_GAMEPADS,
+    },
+    keys::Key,
+    mouse::MouseButton,
+    DonkeyError, Vector2, Window,
+};
+
+/// How far a gamepad axis must be pushed for a [Binding::GamepadAxis] to
+/// count as pressed
+pub const AXIS_PRESS_THRESHOLD: f32 = 0.5;
+
+/// A physical input that can trigger an action. The string form, used in
+/// config files, is `key:<Key>`, `mouse:<MouseButton>`,
+/// `pad<N>:<GamepadButton>`, or `pad<N>:<GamepadAxis>+` and
+/// `pad<N>:<GamepadAxis>-` for an axis pushed in either direction
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum Binding {
+    Key(Key),
+    Mouse(MouseButton),
+    GamepadButton {
+        gamepad: i32,
+        button: GamepadButton,
+    },
+    /// A gamepad axis pushed past [AXIS_PRESS_THRESHOLD], in the positive
+    /// direction if `positive` is true
+    GamepadAxis {
+        gamepad: i32,
+        axis: GamepadAxis,
+        positive: bool,
+    },
+}
+
+impl Binding {
+    /// Check if the input is currently held
+    pub fn is_down(&self, win: &Window) -> bool {
+        match *self {
+            Binding::Key(key) => win.is_key_down(key),
+            Binding::Mouse(button) => win.is_mouse_button_down(button),
+            Binding::GamepadButton { gamepad, button } => win
+                .gamepad(gamepad)
+                .is_some_and(|pad| pad.is_button_down(button)),
+            Binding::GamepadAxis { gamepad, axis, positive } => {
+                win.gamepad(gamepad).is_some_and(|pad| {
+                    let value = pad.axis(axis);
+                    if positive {
+                        value >= AXIS_PRESS_THRESHOLD
+                    } else {
+                        value <= -AXIS_PRESS_THRESHOLD
+                    }
+                })
+            }
+        }
+    }
+}
+
+impl Display for Binding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Binding::Key(key) => write!(f, "key:{key}"),
+            Binding::Mouse(button) => write!(f, "mouse:{button}"),
+            Binding::GamepadButton { gamepad, button } => {
+                write!(f, "pad{gamepad}:{button}")
+            }
+            Binding::GamepadAxis { gamepad, axis, positive } => {
+                let sign = if *positive { '+' } else { '-' };
+                write!(f, "pad{gamepad}:{axis}{sign}")
+            }
+        }
+    }
+}
+
+impl FromStr for Binding {
+    type Err = DonkeyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || DonkeyError::UnknownName {
+            kind: "Binding",
+            name: s.to_owned(),
+        };
+        let (device, input) = s.split_once(':').ok_or_else(err)?;
+        match device {
+            "key" => Ok(Binding::Key(input.parse()?)),
+            "mouse" => Ok(Binding::Mouse(input.parse()?)),
+            _ => {
+                let gamepad = parse_gamepad(device).ok_or_else(err)?;
+                if let Some(axis) = input.strip_suffix('+') {
+                    let axis = axis.parse()?;
+                    Ok(Binding::GamepadAxis { gamepad, axis, positive: true })
+                } else if let Some(axis) = input.strip_suffix('-') {
+                    let axis = axis.parse()?;
+                    Ok(Binding::GamepadAxis { gamepad, axis, positive: false })
+                } else {
+                    let button = input.parse()?;
+                    Ok(Binding::GamepadButton { gamepad, button })
+                }
+            }
+        }
+    }
+}
+
+impl TryFrom<String> for Binding {
+    type Error = DonkeyError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<Binding> for String {
+    fn from(value: Binding) -> Self {
+        value.to_string()
+    }
+}
+
+/// Parse the gamepad index out of a device name like `pad0`, which must be
+/// below [MAX_GAMEPADS]
+fn parse_gamepad(device: &str) -> Option<i32> {
+    let id = device.strip_prefix("pad")?.parse().ok()?;
+    (0..MAX_GAMEPADS).contains(&id).then_some(id)
+}
+
+/// A gamepad axis read as an analog value. The string form is
+/// `pad<N>:<GamepadAxis>`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct AnalogAxis {
+    pub gamepad: i32,
+    pub axis: GamepadAxis,
+}
+
+impl Display for AnalogAxis {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "pad{}:{}", self.gamepad, self.axis)
+    }
+}
+
+impl FromStr for AnalogAxis {
+    type Err = DonkeyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || DonkeyError::UnknownName {
+            kind: "AnalogAxis",
+            name: s.to_owned(),
+        };
+        let (device, axis) = s.split_once(':').ok_or_else(err)?;
+        let gamepad = parse_gamepad(device).ok_or_else(err)?;
+        Ok(AnalogAxis { gamepad, axis: axis.parse()? })
+    }
+}
+
+impl TryFrom<String> for AnalogAxis {
+    type Error = DonkeyError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<AnalogAxis> for String {
+    fn from(value: AnalogAxis) -> Self {
+        value.to_string()
+    }
+}
+
+/// An input that produces a value from -1 to 1
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum AxisBinding {
+    /// -1 while `negative` is held, 1 while `positive` is held, and 0 when
+    /// both or neither are
+    Buttons {
+        negative: Binding,
+        positive: Binding,
+    },
+    /// A gamepad axis, with [DEFAULT_DEADZONE] applied and the rest of the
+    /// range rescaled to start from 0 at its edge
+    Analog(AnalogAxis),
+}
+
+impl AxisBinding {
+    pub fn value(&self, win: &Window) -> f32 {
+        match self {
+            AxisBinding::Buttons { negative, positive } => {
+                let neg = if negative.is_down(win) { 1.0 } else { 0.0 };
+                let pos = if positive.is_down(win) { 1.0 } else { 0.0 };
+                pos - neg
+            }
+            AxisBinding::Analog(AnalogAxis { gamepad, axis }) => {
+                let x =
+                    win.gamepad(*gamepad).map_or(0.0, |pad| pad.axis(*axis));
+                apply_deadzone(Vector2 { x, y: 0.0 }, DEFAULT_DEADZONE).x
+            }
+        }
+    }
+}
+
+/// The per-frame state of a single action
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct ActionState {
+    down: bool,
+    was_down: bool,
+}
+
+impl ActionState {
+    fn update(&mut self, down: bool) {
+        self.was_down = self.down;
+        self.down = down;
+    }
+
+    fn pressed(&self) -> bool {
+        self.down && !self.was_down
+    }
+
+    fn released(&self) -> bool {
+        !self.down && self.was_down
+    }
+}
+
+/// A set of named actions and axes and their bindings. Call
+/// [Actions::update] once per frame before querying them
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Actions {
+    #[serde(default)]
+    actions: BTreeMap<String, Vec<Binding>>,
+    #[serde(default)]
+    axes: BTreeMap<String, Vec<AxisBinding>>,
+    #[serde(skip)]
+    states: HashMap<String, ActionState>,
+    #[serde(skip)]
+    values: HashMap<String, f32>,
+}
+
+impl Actions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add `binding` to the action `name`, declaring it if needed
+    pub fn bind(&mut self, name: &str, binding: Binding) -> &mut Self {
+        self.actions
+            .entry(name.to_owned())
+            .or_default()
+            .push(binding);
+        self
+    }
+
+    /// Add `binding` to the axis `name`, declaring it if needed
+    pub fn bind_axis(&mut self, name: &str, binding: AxisBinding) -> &mut Self {
+        self.axes.entry(name.to_owned()).or_default().push(binding);
+        self
+    }
+
+    /// Replace all of the bindings for the action `name`
+    pub fn rebind(&mut self, name: &str, bindings: Vec<Binding>) {
+        self.actions.insert(name.to_owned(), bindings);
+    }
+
+    /// Replace all of the bindings for the axis `name`
+    pub fn rebind_axis(&mut self, name: &str, bindings: Vec<AxisBinding>) {
+        self.axes.insert(name.to_owned(), bindings);
+    }
+
+    /// The bindings for the action `name`
+    pub fn bindings(&self, name: &str) -> &[Binding] {
+        self.actions.get(name).map_or(&[], Vec::as_slice)
+    }
+
+    /// The bindings for the axis `name`
+    pub fn axis_bindings(&self, name: &str) -> &[AxisBinding] {
+        self.axes.get(name).map_or(&[], Vec::as_slice)
+    }
+
+    /// Sample the current state of every binding. Call this once per frame
+    pub fn update(&mut self, win: &Window) {
+        for (name, bindings) in &self.actions {
+            let down = bindings.iter().any(|b| b.is_down(win));
+            self.states.entry(name.clone()).or_default().update(down);
+        }
+        for (name, bindings) in &self.axes {
+            // take the largest input rather than the sum so that holding a
+            // key and pushing a stick doesn't exceed 1
+            let value = bindings.iter().map(|b| b.value(win)).fold(
+                0.0_f32,
+                |acc, v| if v.abs() > acc.abs() { v } else { acc },
+            );
+            self.values.insert(name.clone(), value);
+        }
+    }
+
+    fn state(&self, name: &str) -> ActionState {
+        self.states.get(name).copied().unwrap_or_default()
+    }
+
+    /// Check if any binding for the action `name` is held
+    pub fn down(&self, name: &str) -> bool {
+        self.state(name).down
+    }
+
+    /// Check if the action `name` started this frame
+    pub fn pressed(&self, name: &str) -> bool {
+        self.state(name).pressed()
+    }
+
+    /// Check if the action `name` ended this frame
+    pub fn released(&self, name: &str) -> bool {
+        self.state(name).released()
+    }
+
+    /// The value of the axis `name`, from -1 to 1
+    pub fn axis(&self, name: &str) -> f32 {
+        self.values.get(name).copied().unwrap_or_default()
+    }
+
+    /// Parse bindings from a TOML string
+    pub fn from_toml(s: &str) -> Result<Self, DonkeyError> {
+        Ok(toml::from_str(s)?)
+    }
+
+    /// Serialize the bindings to a TOML string
+    pub fn to_toml(&self) -> Result<String, DonkeyError> {
+        Ok(toml::to_string(self)?)
+    }
+
+    /// Load bindings from the TOML file at `path`
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, DonkeyError> {
+        Self::from_toml(&std::fs::read_to_string(path)?)
+    }
+
+    /// Save the bindings to the TOML file at `path`
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), DonkeyError> {
+        Ok(std::fs::write(path, self.to_toml()?)?)
+    }
+}
+
+/// The state of a gamepad captured in an [InputState]
+#[derive(Clone, Debug, Default)]
+pub struct GamepadState {
+    pub buttons_down: HashSet<GamepadButton>,
+    /// Raw axis values, without a deadzone applied
+    pub axes: HashMap<GamepadAxis, f32>,
+}
+
+/// A snapshot of the keyboard, mouse, and first connected gamepad for a single
+/// frame. This lets code like [crate::camera::CameraController]s be driven
+/// without a [Window], for example from a replay or a test
+#[derive(Clone, Debug)]
+pub struct InputState {
+    pub keys_down: HashSet<Key>,
+    pub keys_pressed: HashSet<Key>,
+    pub mouse_buttons_down: HashSet<MouseButton>,
+    pub mouse_buttons_pressed: HashSet<MouseButton>,
+    pub mouse_position: Vector2,
+    pub mouse_delta: Vector2,
+    pub mouse_wheel: f32,
+    /// Width and height of the screen in pixels
+    pub screen_size: Vector2,
+    pub gamepad: Option<GamepadState>,
+}
+
+impl Default for InputState {
+    fn default() -> Self {
+        Self {
+            keys_down: HashSet::new(),
+            keys_pressed: HashSet::new(),
+            mouse_buttons_down: HashSet::new(),
+            mouse_buttons_pressed: HashSet::new(),
+            mouse_position: Vector2 { x: 0.0, y: 0.0 },
+            mouse_delta: Vector2 { x: 0.0, y: 0.0 },
+            mouse_wheel: 0.0,
+            screen_size: Vector2 { x: 0.0, y: 0.0 },
+            gamepad: None,
+        }
+    }
+}
+
+impl InputState {
+    /// Capture the current input state of `win`
+    pub fn capture(win: &Window) -> Self {
+        let keys_down = Key::ALL
+            .iter()
+            .copied()
+            .filter(|&k| win.is_key_down(k))
+            .collect();
+        let keys_pressed = Key::ALL
+            .iter()
+            .copied()
+            .filter(|&k| win.is_key_pressed(k))
+            .collect();
+        let mouse_buttons_down = MouseButton::ALL
+            .iter()
+            .copied()
+            .filter(|&b| win.is_mouse_button_down(b))
+            .collect();
+        let mouse_buttons_pressed = MouseButton::ALL
+            .iter()
+            .copied()
+            .filter(|&b| win.is_mouse_button_pressed(b))
+            .collect();
+        let gamepad = win.gamepads().next().map(|pad| GamepadState {
+            buttons_down: GamepadButton::ALL
+                .iter()
+                .copied()
+                .filter(|&b| pad.is_button_down(b))
+                .collect(),
+            axes: GamepadAxis::ALL.iter().map(|&a| (a, pad.axis(a))).collect(),
+        });
+        Self {
+            keys_down,
+            keys_pressed,
+            mouse_buttons_down,
+            mouse_buttons_pressed,
+            mouse_position: win.mouse_position(),
+            mouse_delta: win.mouse_delta(),
+            mouse_wheel: win.mouse_wheel_move(),
+            screen_size: Vector2 {
+                x: win.get_screen_width() as f32,
+                y: win.get_screen_height() as f32,
+            },
+            gamepad,
+        }
+    }
+
+    pub fn is_key_down(&self, key: Key) -> bool {
+        self.keys_down.contains(&key)
+    }
+
+    pub fn is_key_pressed(&self, key: Key) -> bool {
+        self.keys_pressed.contains(&key)
+    }
+
+    pub fn is_mouse_button_down(&self, button: MouseButton) -> bool {
+        self.mouse_buttons_down.contains(&button)
+    }
+
+    pub fn is_mouse_button_pressed(&self, button: MouseButton) -> bool {
+        self.mouse_buttons_pressed.contains(&button)
+    }
+
+    /// The raw value of `axis` on the captured gamepad, or 0 if there is none
+    pub fn gamepad_axis(&self, axis: GamepadAxis) -> f32 {
+        self.gamepad
+            .as_ref()
+            .and_then(|pad| pad.axes.get(&axis).copied())
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_bindings() {
+        let tests = [
+            ("key:Space", Binding::Key(Key::Space)),
+            ("mouse:right", Binding::Mouse(MouseButton::Right)),
+            (
+                "pad1:RightFaceDown",
+                Binding::GamepadButton {
+                    gamepad: 1,
+                    button: GamepadButton::RightFaceDown,
+                },
+            ),
+            (
+                "pad0:LeftY-",
+                Binding::GamepadAxis {
+                    gamepad: 0,
+                    axis: GamepadAxis::LeftY,
+                    positive: false,
+                },
+            ),
+        ];
+        for (s, want) in tests {
+            let got: Binding = s.parse().unwrap();
+            assert_eq!(got, want);
+            assert!(got.to_string().eq_ignore_ascii_case(s));
+        }
+        for s in ["Space", "key:Spacebar", "padx:Middle", "joystick:A"] {
+            assert!(s.parse::<Binding>().is_err(), "{s}");
+        }
+    }
+
+    #[test]
+    fn gamepad_out_of_range() {
+        for s in ["pad-1:A", "pad-1:RightFaceDown", "pad4:LeftX+"] {
+            assert!(s.parse::<Binding>().is_err(), "{s}");
+        }
+        for s in ["pad-1:LeftX", "pad4:LeftX"] {
+            assert!(s.parse::<AnalogAxis>().is_err(), "{s}");
+        }
+        assert!("pad3:LeftX".parse::<AnalogAxis>().is_ok());
+    }
+
+    #[test]
+    fn toml_round_trip() {
+        let s = r#"
+[actions]
+fire = ["mouse:Left"]
+jump = ["key:Space", "pad0:RightFaceDown"]
+
+[axes]
+move_x = [{ negative = "key:A", positive = "key:D" }, "pad0:LeftX"]
+"#;
+        let actions = Actions::from_toml(s).unwrap();
+        assert_eq!(
+            actions.bindings("fire"),
+            [Binding::Mouse(MouseButton::Left)]
+        );
+        assert_eq!(actions.bindings("jump").len(), 2);
+        assert_eq!(
+            actions.axis_bindings("move_x"),
+            [
+                AxisBinding::Buttons {
+                    negative: Binding::Key(Key::A),
+                    positive: Binding::Key(Key::D),
+                },
+                AxisBinding::Analog(AnalogAxis {
+                    gamepad: 0,
+                    axis: GamepadAxis::LeftX
+                }),
+            ]
+        );
+
+        let got = Actions::from_toml(&actions.to_toml().unwrap()).unwrap();
+        assert_eq!(got.actions, actions.actions);
+        assert_eq!(got.axes, actions.axes);
+    }
+
+    #[test]
+    fn action_edges() {
+        let mut state = ActionState::default();
+        state.update(true);
+        assert!(state.pressed() && state.down);
+        state.update(true);
+        assert!(!state.pressed() && state.down);
+        state.update(false);
+        assert!(state.released() && !state.down);
+        state.update(false);
+        assert!(!state.released());
+    }
+}
@@ -0,0 +1,69 @@
+//! Keyboard input
+
+use std::ffi::c_int;
+
+use raylib_sys::{
+    GetCharPressed, GetKeyPressed, IsKeyDown, IsKeyPressed, IsKeyPressedRepeat,
+    IsKeyReleased, IsKeyUp,
+};
+
+use crate::{keys::Key, Window};
+
+impl Window {
+    /// Check if a key is being pressed
+    pub fn is_key_down(&self, key: Key) -> bool {
+        unsafe { IsKeyDown(key as c_int) }
+    }
+
+    /// Check if a key has been pressed once
+    pub fn is_key_pressed(&self, key: Key) -> bool {
+        unsafe { IsKeyPressed(key as c_int) }
+    }
+
+    /// Check if a key has been pressed again by the OS key repeat while held
+    /// down
+    pub fn is_key_pressed_repeat(&self, key: Key) -> bool {
+        unsafe { IsKeyPressedRepeat(key as c_int) }
+    }
+
+    /// Check if a key has been released once
+    pub fn is_key_released(&self, key: Key) -> bool {
+        unsafe { IsKeyReleased(key as c_int) }
+    }
+
+    /// Check if a key is not being pressed
+    pub fn is_key_up(&self, key: Key) -> bool {
+        unsafe { IsKeyUp(key as c_int) }
+    }
+
+    /// Drain the queue of keys pressed this frame, in the order they were
+    /// pressed. Keys without a [Key] variant are skipped
+    pub fn pressed_keys(&self) -> impl Iterator<Item = Key> + '_ {
+        std::iter::from_fn(|| loop {
+            match unsafe { GetKeyPressed() } {
+                0 => return None,
+                code => {
+                    if let Ok(key) = Key::try_from(code) {
+                        return Some(key);
+                    }
+                }
+            }
+        })
+    }
+
+    /// Drain the queue of characters typed this frame, for text input. Unlike
+    /// [Window::pressed_keys], this respects the keyboard layout and
+    /// modifiers, so `Shift+a` yields `'A'`
+    pub fn pressed_chars(&self) -> impl Iterator<Item = char> + '_ {
+        std::iter::from_fn(|| loop {
+            match unsafe { GetCharPressed() } {
+                0 => return None,
+                code => {
+                    if let Some(c) = char::from_u32(code as u32) {
+                        return Some(c);
+                    }
+                }
+            }
+        })
+    }
+}
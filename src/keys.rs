@@ -1,40 +1,226 @@
-#[repr(i32)]
-pub enum Key {
-    A = raylib_sys::KeyboardKey_KEY_A as i32,
-    B = raylib_sys::KeyboardKey_KEY_B as i32,
-    C = raylib_sys::KeyboardKey_KEY_C as i32,
-    D = raylib_sys::KeyboardKey_KEY_D as i32,
-    E = raylib_sys::KeyboardKey_KEY_E as i32,
-    F = raylib_sys::KeyboardKey_KEY_F as i32,
-    G = raylib_sys::KeyboardKey_KEY_G as i32,
-    H = raylib_sys::KeyboardKey_KEY_H as i32,
-    I = raylib_sys::KeyboardKey_KEY_I as i32,
-    J = raylib_sys::KeyboardKey_KEY_J as i32,
-    K = raylib_sys::KeyboardKey_KEY_K as i32,
-    L = raylib_sys::KeyboardKey_KEY_L as i32,
-    M = raylib_sys::KeyboardKey_KEY_M as i32,
-    N = raylib_sys::KeyboardKey_KEY_N as i32,
-    O = raylib_sys::KeyboardKey_KEY_O as i32,
-    P = raylib_sys::KeyboardKey_KEY_P as i32,
-    Q = raylib_sys::KeyboardKey_KEY_Q as i32,
-    R = raylib_sys::KeyboardKey_KEY_R as i32,
-    S = raylib_sys::KeyboardKey_KEY_S as i32,
-    T = raylib_sys::KeyboardKey_KEY_T as i32,
-    U = raylib_sys::KeyboardKey_KEY_U as i32,
-    V = raylib_sys::KeyboardKey_KEY_V as i32,
-    W = raylib_sys::KeyboardKey_KEY_W as i32,
-    X = raylib_sys::KeyboardKey_KEY_X as i32,
-    Y = raylib_sys::KeyboardKey_KEY_Y as i32,
-    Z = raylib_sys::KeyboardKey_KEY_Z as i32,
-    // Arrow keys
-    Left = raylib_sys::KeyboardKey_KEY_LEFT as i32,
-    Right = raylib_sys::KeyboardKey_KEY_RIGHT as i32,
-    Up = raylib_sys::KeyboardKey_KEY_UP as i32,
-    Down = raylib_sys::KeyboardKey_KEY_DOWN as i32,
-    // Modifiers
-    LShift = raylib_sys::KeyboardKey_KEY_LEFT_SHIFT as i32,
-    LControl = raylib_sys::KeyboardKey_KEY_LEFT_CONTROL as i32,
-    // Whitespace
-    Enter = raylib_sys::KeyboardKey_KEY_ENTER as i32,
-    Space = raylib_sys::KeyboardKey_KEY_SPACE as i32,
+//! Keyboard keys, generated from raylib's KeyboardKey by
+//! scripts/generate_keys.awk. Do not edit by hand
+
+raylib_enum! {
+    /// A key on a US keyboard layout
+    pub enum Key {
+        /// '
+        Apostrophe = raylib_sys::KeyboardKey_KEY_APOSTROPHE,
+        /// ,
+        Comma = raylib_sys::KeyboardKey_KEY_COMMA,
+        /// -
+        Minus = raylib_sys::KeyboardKey_KEY_MINUS,
+        /// .
+        Period = raylib_sys::KeyboardKey_KEY_PERIOD,
+        /// /
+        Slash = raylib_sys::KeyboardKey_KEY_SLASH,
+        /// 0
+        Zero = raylib_sys::KeyboardKey_KEY_ZERO,
+        /// 1
+        One = raylib_sys::KeyboardKey_KEY_ONE,
+        /// 2
+        Two = raylib_sys::KeyboardKey_KEY_TWO,
+        /// 3
+        Three = raylib_sys::KeyboardKey_KEY_THREE,
+        /// 4
+        Four = raylib_sys::KeyboardKey_KEY_FOUR,
+        /// 5
+        Five = raylib_sys::KeyboardKey_KEY_FIVE,
+        /// 6
+        Six = raylib_sys::KeyboardKey_KEY_SIX,
+        /// 7
+        Seven = raylib_sys::KeyboardKey_KEY_SEVEN,
+        /// 8
+        Eight = raylib_sys::KeyboardKey_KEY_EIGHT,
+        /// 9
+        Nine = raylib_sys::KeyboardKey_KEY_NINE,
+        /// ;
+        Semicolon = raylib_sys::KeyboardKey_KEY_SEMICOLON,
+        /// =
+        Equal = raylib_sys::KeyboardKey_KEY_EQUAL,
+        /// A | a
+        A = raylib_sys::KeyboardKey_KEY_A,
+        /// B | b
+        B = raylib_sys::KeyboardKey_KEY_B,
+        /// C | c
+        C = raylib_sys::KeyboardKey_KEY_C,
+        /// D | d
+        D = raylib_sys::KeyboardKey_KEY_D,
+        /// E | e
+        E = raylib_sys::KeyboardKey_KEY_E,
+        /// F | f
+        F = raylib_sys::KeyboardKey_KEY_F,
+        /// G | g
+        G = raylib_sys::KeyboardKey_KEY_G,
+        /// H | h
+        H = raylib_sys::KeyboardKey_KEY_H,
+        /// I | i
+        I = raylib_sys::KeyboardKey_KEY_I,
+        /// J | j
+        J = raylib_sys::KeyboardKey_KEY_J,
+        /// K | k
+        K = raylib_sys::KeyboardKey_KEY_K,
+        /// L | l
+        L = raylib_sys::KeyboardKey_KEY_L,
+        /// M | m
+        M = raylib_sys::KeyboardKey_KEY_M,
+        /// N | n
+        N = raylib_sys::KeyboardKey_KEY_N,
+        /// O | o
+        O = raylib_sys::KeyboardKey_KEY_O,
+        /// P | p
+        P = raylib_sys::KeyboardKey_KEY_P,
+        /// Q | q
+        Q = raylib_sys::KeyboardKey_KEY_Q,
+        /// R | r
+        R = raylib_sys::KeyboardKey_KEY_R,
+        /// S | s
+        S = raylib_sys::KeyboardKey_KEY_S,
+        /// T | t
+        T = raylib_sys::KeyboardKey_KEY_T,
+        /// U | u
+        U = raylib_sys::KeyboardKey_KEY_U,
+        /// V | v
+        V = raylib_sys::KeyboardKey_KEY_V,
+        /// W | w
+        W = raylib_sys::KeyboardKey_KEY_W,
+        /// X | x
+        X = raylib_sys::KeyboardKey_KEY_X,
+        /// Y | y
+        Y = raylib_sys::KeyboardKey_KEY_Y,
+        /// Z | z
+        Z = raylib_sys::KeyboardKey_KEY_Z,
+        /// [
+        LeftBracket = raylib_sys::KeyboardKey_KEY_LEFT_BRACKET,
+        /// '\'
+        Backslash = raylib_sys::KeyboardKey_KEY_BACKSLASH,
+        /// ]
+        RightBracket = raylib_sys::KeyboardKey_KEY_RIGHT_BRACKET,
+        /// `
+        Grave = raylib_sys::KeyboardKey_KEY_GRAVE,
+        /// Space
+        Space = raylib_sys::KeyboardKey_KEY_SPACE,
+        /// Esc
+        Escape = raylib_sys::KeyboardKey_KEY_ESCAPE,
+        /// Enter
+        Enter = raylib_sys::KeyboardKey_KEY_ENTER,
+        /// Tab
+        Tab = raylib_sys::KeyboardKey_KEY_TAB,
+        /// Backspace
+        Backspace = raylib_sys::KeyboardKey_KEY_BACKSPACE,
+        /// Ins
+        Insert = raylib_sys::KeyboardKey_KEY_INSERT,
+        /// Del
+        Delete = raylib_sys::KeyboardKey_KEY_DELETE,
+        /// Cursor right
+        Right = raylib_sys::KeyboardKey_KEY_RIGHT,
+        /// Cursor left
+        Left = raylib_sys::KeyboardKey_KEY_LEFT,
+        /// Cursor down
+        Down = raylib_sys::KeyboardKey_KEY_DOWN,
+        /// Cursor up
+        Up = raylib_sys::KeyboardKey_KEY_UP,
+        /// Page up
+        PageUp = raylib_sys::KeyboardKey_KEY_PAGE_UP,
+        /// Page down
+        PageDown = raylib_sys::KeyboardKey_KEY_PAGE_DOWN,
+        /// Home
+        Home = raylib_sys::KeyboardKey_KEY_HOME,
+        /// End
+        End = raylib_sys::KeyboardKey_KEY_END,
+        /// Caps lock
+        CapsLock = raylib_sys::KeyboardKey_KEY_CAPS_LOCK,
+        /// Scroll down
+        ScrollLock = raylib_sys::KeyboardKey_KEY_SCROLL_LOCK,
+        /// Num lock
+        NumLock = raylib_sys::KeyboardKey_KEY_NUM_LOCK,
+        /// Print screen
+        PrintScreen = raylib_sys::KeyboardKey_KEY_PRINT_SCREEN,
+        /// Pause
+        Pause = raylib_sys::KeyboardKey_KEY_PAUSE,
+        /// F1
+        F1 = raylib_sys::KeyboardKey_KEY_F1,
+        /// F2
+        F2 = raylib_sys::KeyboardKey_KEY_F2,
+        /// F3
+        F3 = raylib_sys::KeyboardKey_KEY_F3,
+        /// F4
+        F4 = raylib_sys::KeyboardKey_KEY_F4,
+        /// F5
+        F5 = raylib_sys::KeyboardKey_KEY_F5,
+        /// F6
+        F6 = raylib_sys::KeyboardKey_KEY_F6,
+        /// F7
+        F7 = raylib_sys::KeyboardKey_KEY_F7,
+        /// F8
+        F8 = raylib_sys::KeyboardKey_KEY_F8,
+        /// F9
+        F9 = raylib_sys::KeyboardKey_KEY_F9,
+        /// F10
+        F10 = raylib_sys::KeyboardKey_KEY_F10,
+        /// F11
+        F11 = raylib_sys::KeyboardKey_KEY_F11,
+        /// F12
+        F12 = raylib_sys::KeyboardKey_KEY_F12,
+        /// Shift left
+        LShift = raylib_sys::KeyboardKey_KEY_LEFT_SHIFT,
+        /// Control left
+        LControl = raylib_sys::KeyboardKey_KEY_LEFT_CONTROL,
+        /// Alt left
+        LAlt = raylib_sys::KeyboardKey_KEY_LEFT_ALT,
+        /// Super left
+        LSuper = raylib_sys::KeyboardKey_KEY_LEFT_SUPER,
+        /// Shift right
+        RShift = raylib_sys::KeyboardKey_KEY_RIGHT_SHIFT,
+        /// Control right
+        RControl = raylib_sys::KeyboardKey_KEY_RIGHT_CONTROL,
+        /// Alt right
+        RAlt = raylib_sys::KeyboardKey_KEY_RIGHT_ALT,
+        /// Super right
+        RSuper = raylib_sys::KeyboardKey_KEY_RIGHT_SUPER,
+        /// KB menu
+        KbMenu = raylib_sys::KeyboardKey_KEY_KB_MENU,
+        /// Keypad 0
+        Kp0 = raylib_sys::KeyboardKey_KEY_KP_0,
+        /// Keypad 1
+        Kp1 = raylib_sys::KeyboardKey_KEY_KP_1,
+        /// Keypad 2
+        Kp2 = raylib_sys::KeyboardKey_KEY_KP_2,
+        /// Keypad 3
+        Kp3 = raylib_sys::KeyboardKey_KEY_KP_3,
+        /// Keypad 4
+        Kp4 = raylib_sys::KeyboardKey_KEY_KP_4,
+        /// Keypad 5
+        Kp5 = raylib_sys::KeyboardKey_KEY_KP_5,
+        /// Keypad 6
+        Kp6 = raylib_sys::KeyboardKey_KEY_KP_6,
+        /// Keypad 7
+        Kp7 = raylib_sys::KeyboardKey_KEY_KP_7,
+        /// Keypad 8
+        Kp8 = raylib_sys::KeyboardKey_KEY_KP_8,
+        /// Keypad 9
+        Kp9 = raylib_sys::KeyboardKey_KEY_KP_9,
+        /// Keypad .
+        KpDecimal = raylib_sys::KeyboardKey_KEY_KP_DECIMAL,
+        /// Keypad /
+        KpDivide = raylib_sys::KeyboardKey_KEY_KP_DIVIDE,
+        /// Keypad *
+        KpMultiply = raylib_sys::KeyboardKey_KEY_KP_MULTIPLY,
+        /// Keypad -
+        KpSubtract = raylib_sys::KeyboardKey_KEY_KP_SUBTRACT,
+        /// Keypad +
+        KpAdd = raylib_sys::KeyboardKey_KEY_KP_ADD,
+        /// Keypad Enter
+        KpEnter = raylib_sys::KeyboardKey_KEY_KP_ENTER,
+        /// Keypad =
+        KpEqual = raylib_sys::KeyboardKey_KEY_KP_EQUAL,
+        /// Android back button
+        Back = raylib_sys::KeyboardKey_KEY_BACK,
+        /// Android menu button
+        Menu = raylib_sys::KeyboardKey_KEY_MENU,
+        /// Android volume up button
+        VolumeUp = raylib_sys::KeyboardKey_KEY_VOLUME_UP,
+        /// Android volume down button
+        VolumeDown = raylib_sys::KeyboardKey_KEY_VOLUME_DOWN,
+    }
 }
@@ -1,52 +1,44 @@
 use std::{
-    error::Error,
-    ffi::{c_int, CString, NulError},
-    fmt::Display,
+    ffi::{c_int, c_uint, CStr, CString},
+    marker::PhantomData,
+    sync::atomic::{AtomicBool, Ordering},
 };
 
-use colors::IntoColor;
-use keys::Key;
-use raylib_sys::{matrix::MatrixRotate, GetScreenHeight};
+use builder::WindowBuilder;
 use raylib_sys::{
-    BeginDrawing, BeginMode3D, CameraMoveToTarget, CameraRoll, ClearBackground,
-    DrawCube, DrawCylinderEx, DrawSphere, DrawText, EndDrawing, EndMode3D,
-    GamepadAxis_GAMEPAD_AXIS_LEFT_X, GamepadAxis_GAMEPAD_AXIS_LEFT_Y,
-    GamepadAxis_GAMEPAD_AXIS_RIGHT_X, GamepadAxis_GAMEPAD_AXIS_RIGHT_Y,
-    GetCameraUp, GetFrameTime, GetGamepadAxisMovement, GetMouseDelta,
-    GetMouseWheelMove, InitWindow, IsGamepadAvailable, IsKeyDown, IsKeyPressed,
-    IsMouseButtonDown, KeyboardKey_KEY_KP_ADD, KeyboardKey_KEY_KP_SUBTRACT,
-    MeasureText, MouseButton_MOUSE_BUTTON_LEFT,
-    MouseButton_MOUSE_BUTTON_MIDDLE, SetTargetFPS, TakeScreenshot,
-    WindowShouldClose, CAMERA_MOUSE_MOVE_SENSITIVITY, CAMERA_MOVE_SPEED,
-    CAMERA_ORBITAL_SPEED, CAMERA_PAN_SPEED, CAMERA_ROTATION_SPEED,
+    CloseWindow, GetFrameTime, GetScreenHeight, GetScreenWidth, InitWindow,
+    IsWindowReady, MeasureText, SetConfigFlags, SetTargetFPS, TakeScreenshot,
+    WindowShouldClose,
 };
 
+pub use error::DonkeyError;
 pub use raylib_sys::camera3d::CameraProjection;
-pub use raylib_sys::{Camera3D, Rectangle, Vector2, Vector3};
+pub use raylib_sys::collision;
+pub use raylib_sys::{
+    BoundingBox, Camera2D, Camera3D, Matrix, Quaternion, Ray, RayCollision,
+    Rectangle, Vector2, Vector3, Vector4,
+};
+
+#[macro_use]
+mod macros;
 
+pub mod builder;
+pub mod camera;
 pub mod colors;
+pub mod draw;
+pub mod error;
+pub mod gamepad;
 pub mod image;
+pub mod input;
+mod keyboard;
 pub mod keys;
+pub mod logging;
+pub mod mouse;
+pub mod picking;
+pub mod threed;
 pub mod twod;
 pub mod window_ex;
 
-#[derive(Debug)]
-pub struct DonkeyError(&'static str);
-
-impl Error for DonkeyError {}
-
-impl Display for DonkeyError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "DonkeyError({})", self.0)
-    }
-}
-
-impl From<NulError> for DonkeyError {
-    fn from(_value: NulError) -> Self {
-        Self("NulError")
-    }
-}
-
 #[derive(PartialEq)]
 #[repr(i32)]
 pub enum CameraMode {
@@ -69,15 +61,54 @@ macro_rules! vector3 {
     };
 }
 
-pub struct Window;
+/// Set while a [Window] is alive, since raylib only supports a single window
+/// per process
+static WINDOW_OPEN: AtomicBool = AtomicBool::new(false);
+
+/// The raylib window and its OpenGL context, closed when dropped. Only one
+/// `Window` can be open at a time, and it cannot be sent to or shared with
+/// another thread.
+pub struct Window {
+    _not_send: PhantomData<*const ()>,
+}
 
 impl Window {
-    pub fn init(width: i32, height: i32, title: &str) -> Self {
-        let title = CString::new(title).unwrap();
+    /// Open a window with the default settings. See [Window::builder] for more
+    /// options
+    pub fn init(
+        width: i32,
+        height: i32,
+        title: &str,
+    ) -> Result<Self, DonkeyError> {
+        Self::builder(width, height, title).build()
+    }
+
+    /// Configure a window before opening it with [WindowBuilder::build]
+    pub fn builder(width: i32, height: i32, title: &str) -> WindowBuilder {
+        WindowBuilder::new(width, height, title)
+    }
+
+    /// Set the config `flags` and open the window, as long as another one is
+    /// not already open
+    fn open(
+        width: i32,
+        height: i32,
+        title: &CStr,
+        flags: c_uint,
+    ) -> Result<Self, DonkeyError> {
+        if WINDOW_OPEN.swap(true, Ordering::SeqCst) {
+            return Err(DonkeyError::WindowAlreadyOpen);
+        }
         unsafe {
+            SetConfigFlags(flags);
             InitWindow(width, height, title.as_ptr());
+            if !IsWindowReady() {
+                CloseWindow();
+                WINDOW_OPEN.store(false, Ordering::SeqCst);
+                return Err(DonkeyError::WindowInit);
+            }
         }
-        Self
+        Ok(Self { _not_send: PhantomData })
     }
 
     pub fn set_target_fps(&self, target: usize) {
@@ -92,323 +123,73 @@ impl Window {
         unsafe { GetFrameTime() }
     }
 
+    pub fn get_screen_width(&self) -> c_int {
+        unsafe { GetScreenWidth() }
+    }
+
     pub fn get_screen_height(&self) -> c_int {
         unsafe { GetScreenHeight() }
     }
 
+    /// Save a screenshot of the current screen to `filename`. Panics if
+    /// `filename` contains a NUL byte; see [Window::try_take_screenshot]
     pub fn take_screenshot(&self, filename: &str) {
-        let filename = CString::new(filename).unwrap();
-        unsafe {
-            TakeScreenshot(filename.as_ptr());
-        }
-    }
-
-    /// Check if a key is being pressed
-    pub fn is_key_down(&self, key: Key) -> bool {
-        unsafe { IsKeyDown(key as i32) }
-    }
-
-    /// Check if a key has been pressed once
-    pub fn is_key_pressed(&self, key: Key) -> bool {
-        unsafe { IsKeyPressed(key as i32) }
-    }
-
-    /// TODO do one of those cool closure things here:
-    /// ```notrust
-    /// win.draw(|canvas| {
-    ///     canvas.clear_background(color);
-    ///     ...
-    /// })
-    /// ```
-    pub fn begin_drawing(&self) {
-        unsafe { BeginDrawing() }
-    }
-
-    pub fn end_drawing(&self) {
-        unsafe { EndDrawing() }
-    }
-
-    // drawing functions
-
-    pub fn clear_background(&self, color: impl IntoColor) {
-        unsafe { ClearBackground(color.into()) }
+        self.try_take_screenshot(filename).unwrap()
     }
 
-    pub fn draw_cube(
+    pub fn try_take_screenshot(
         &self,
-        center: Vector3,
-        width: f32,
-        height: f32,
-        length: f32,
-        color: impl IntoColor,
-    ) {
-        unsafe { DrawCube(center, width, height, length, color.into()) }
+        filename: &str,
+    ) -> Result<(), DonkeyError> {
+        let filename = CString::new(filename)?;
+        unsafe {
+            TakeScreenshot(filename.as_ptr());
+        }
+        Ok(())
     }
 
-    pub fn draw_sphere(
-        &self,
-        center: Vector3,
-        radius: f32,
-        color: impl IntoColor,
-    ) {
-        unsafe { DrawSphere(center, radius, color.into()) }
+    /// Measure the width of `text` for the default font with `font_size`.
+    /// panics if `text` cannot be converted to a [CString]; see
+    /// [Window::try_measure_text]
+    pub fn measure_text(&self, text: &str, font_size: usize) -> c_int {
+        self.try_measure_text(text, font_size).unwrap()
     }
 
-    /// draw a cylinder from `start` to `end` with constant `radius`
-    pub fn draw_cylinder(
+    pub fn try_measure_text(
         &self,
-        start: Vector3,
-        end: Vector3,
-        radius: f32,
-        color: impl IntoColor,
-    ) {
-        unsafe { DrawCylinderEx(start, end, radius, radius, 8, color.into()) }
-    }
-
-    // end drawing
-
-    pub fn begin_mode3d(&self, camera: Camera3D) {
-        unsafe { BeginMode3D(camera) }
+        text: &str,
+        font_size: usize,
+    ) -> Result<c_int, DonkeyError> {
+        let s = CString::new(text)?;
+        unsafe { Ok(MeasureText(s.as_ptr(), font_size as c_int)) }
     }
+}
 
-    /// I didn't like the default UpdateCamera from rcamera, so this is a
-    /// modification to require holding down the mouse button to rotate
-    pub fn update_camera(&self, camera: &mut Camera3D, mode: CameraMode) {
-        unsafe {
-            use CameraMode as CM;
-            let mouse_position_delta = GetMouseDelta();
-            let move_in_world_plane =
-                matches!(mode, CM::FirstPerson | CM::ThirdPerson);
-            let rotate_around_target =
-                matches!(mode, CM::ThirdPerson | CM::Orbital);
-            let lock_view =
-                matches!(mode, CM::FirstPerson | CM::ThirdPerson | CM::Orbital);
-            let rotate_up = false;
-
-            if mode == CM::Orbital {
-                // Orbital can just orbit
-                let rotation = MatrixRotate(
-                    GetCameraUp(camera),
-                    CAMERA_ORBITAL_SPEED * GetFrameTime(),
-                );
-                let mut view = camera.position - camera.target;
-                view = view.transform(rotation);
-                camera.position = camera.target + view;
-            } else {
-                // Camera rotation
-                if self.is_key_down(Key::Down) {
-                    camera.pitch(
-                        -CAMERA_ROTATION_SPEED,
-                        lock_view,
-                        rotate_around_target,
-                        rotate_up,
-                    )
-                };
-                if self.is_key_down(Key::Up) {
-                    camera.pitch(
-                        CAMERA_ROTATION_SPEED,
-                        lock_view,
-                        rotate_around_target,
-                        rotate_up,
-                    )
-                };
-                if self.is_key_down(Key::Right) {
-                    camera.yaw(-CAMERA_ROTATION_SPEED, rotate_around_target)
-                };
-                if self.is_key_down(Key::Left) {
-                    camera.yaw(CAMERA_ROTATION_SPEED, rotate_around_target)
-                };
-                if self.is_key_down(Key::Q) {
-                    CameraRoll(camera, -CAMERA_ROTATION_SPEED)
-                };
-                if self.is_key_down(Key::E) {
-                    CameraRoll(camera, CAMERA_ROTATION_SPEED)
-                };
-
-                // Camera movement
-                if !IsGamepadAvailable(0) {
-                    // Camera pan (for CAMERA_FREE)
-                    if (mode == CM::Free)
-                        && (IsMouseButtonDown(
-                            MouseButton_MOUSE_BUTTON_MIDDLE as i32,
-                        ))
-                    {
-                        let mouse_delta = GetMouseDelta();
-                        if mouse_delta.x > 0.0 {
-                            camera.move_right(
-                                CAMERA_PAN_SPEED,
-                                move_in_world_plane,
-                            );
-                        }
-                        if mouse_delta.x < 0.0 {
-                            camera.move_right(
-                                -CAMERA_PAN_SPEED,
-                                move_in_world_plane,
-                            );
-                        }
-                        if mouse_delta.y > 0.0 {
-                            camera.move_up(-CAMERA_PAN_SPEED);
-                        }
-                        if mouse_delta.y < 0.0 {
-                            camera.move_up(CAMERA_PAN_SPEED);
-                        }
-                    } else {
-                        // Mouse support
-                        if IsMouseButtonDown(
-                            MouseButton_MOUSE_BUTTON_LEFT as i32,
-                        ) {
-                            camera.yaw(
-                                -mouse_position_delta.x
-                                    * CAMERA_MOUSE_MOVE_SENSITIVITY,
-                                rotate_around_target,
-                            );
-                            camera.pitch(
-                                -mouse_position_delta.y
-                                    * CAMERA_MOUSE_MOVE_SENSITIVITY,
-                                lock_view,
-                                rotate_around_target,
-                                rotate_up,
-                            );
-                        }
-                    }
-
-                    // Keyboard support
-                    if self.is_key_down(Key::W) {
-                        camera.move_forward(
-                            CAMERA_MOVE_SPEED,
-                            move_in_world_plane,
-                        );
-                    }
-                    if self.is_key_down(Key::A) {
-                        camera.move_right(
-                            -CAMERA_MOVE_SPEED,
-                            move_in_world_plane,
-                        );
-                    }
-                    if self.is_key_down(Key::S) {
-                        camera.move_forward(
-                            -CAMERA_MOVE_SPEED,
-                            move_in_world_plane,
-                        );
-                    }
-                    if self.is_key_down(Key::D) {
-                        camera
-                            .move_right(CAMERA_MOVE_SPEED, move_in_world_plane);
-                    }
-                } else {
-                    // Gamepad controller support
-                    camera.yaw(
-                        -(GetGamepadAxisMovement(
-                            0,
-                            GamepadAxis_GAMEPAD_AXIS_RIGHT_X as i32,
-                        ) * 2.0)
-                            * CAMERA_MOUSE_MOVE_SENSITIVITY,
-                        rotate_around_target,
-                    );
-                    camera.pitch(
-                        -(GetGamepadAxisMovement(
-                            0,
-                            GamepadAxis_GAMEPAD_AXIS_RIGHT_Y as i32,
-                        ) * 2.0)
-                            * CAMERA_MOUSE_MOVE_SENSITIVITY,
-                        lock_view,
-                        rotate_around_target,
-                        rotate_up,
-                    );
-
-                    if GetGamepadAxisMovement(
-                        0,
-                        GamepadAxis_GAMEPAD_AXIS_LEFT_Y as i32,
-                    ) <= -0.25
-                    {
-                        camera.move_forward(
-                            CAMERA_MOVE_SPEED,
-                            move_in_world_plane,
-                        );
-                    }
-                    if GetGamepadAxisMovement(
-                        0,
-                        GamepadAxis_GAMEPAD_AXIS_LEFT_X as i32,
-                    ) <= -0.25
-                    {
-                        camera.move_right(
-                            -CAMERA_MOVE_SPEED,
-                            move_in_world_plane,
-                        );
-                    }
-                    if GetGamepadAxisMovement(
-                        0,
-                        GamepadAxis_GAMEPAD_AXIS_LEFT_Y as i32,
-                    ) >= 0.25
-                    {
-                        camera.move_forward(
-                            -CAMERA_MOVE_SPEED,
-                            move_in_world_plane,
-                        );
-                    }
-                    if GetGamepadAxisMovement(
-                        0,
-                        GamepadAxis_GAMEPAD_AXIS_LEFT_X as i32,
-                    ) >= 0.25
-                    {
-                        camera
-                            .move_right(CAMERA_MOVE_SPEED, move_in_world_plane);
-                    }
-                }
-
-                if mode == CM::Free {
-                    if self.is_key_down(Key::Space) {
-                        camera.move_up(CAMERA_MOVE_SPEED);
-                    }
-                    if self.is_key_down(Key::LControl) {
-                        camera.move_up(-CAMERA_MOVE_SPEED);
-                    }
-                }
-            }
-
-            if matches!(mode, CM::ThirdPerson | CM::Orbital | CM::Free) {
-                // Zoom target distance
-                CameraMoveToTarget(camera, -GetMouseWheelMove());
-                if IsKeyPressed(KeyboardKey_KEY_KP_SUBTRACT as i32) {
-                    CameraMoveToTarget(camera, 2.0);
-                }
-                if IsKeyPressed(KeyboardKey_KEY_KP_ADD as i32) {
-                    CameraMoveToTarget(camera, -2.0);
-                }
-            }
-        }
+impl Drop for Window {
+    fn drop(&mut self) {
+        unsafe { CloseWindow() }
+        WINDOW_OPEN.store(false, Ordering::SeqCst);
     }
+}
 
-    pub fn end_mode3d(&self) {
-        unsafe { EndMode3D() }
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    pub fn draw_text(
-        &self,
-        text: impl Into<Vec<u8>>,
-        x: usize,
-        y: usize,
-        font_size: usize,
-        color: impl IntoColor,
-    ) {
-        unsafe {
-            let cstr = CString::new(text).unwrap();
-            DrawText(
-                cstr.as_ptr(),
-                x as c_int,
-                y as c_int,
-                font_size as c_int,
-                color.into(),
-            );
+    #[test]
+    #[ignore = "requires a display"]
+    fn reopen_window() {
+        for _ in 0..3 {
+            let win = Window::init(100, 100, "reopen").unwrap();
+            drop(win);
         }
     }
 
-    /// Measure the width of `text` for the default font with `font_size`.
-    /// panics if `text` cannot be converted to a [CString]
-    pub fn measure_text(&self, text: &str, font_size: usize) -> c_int {
-        unsafe {
-            let s = CString::new(text).unwrap();
-            MeasureText(s.as_ptr(), font_size as c_int)
-        }
+    #[test]
+    #[ignore = "requires a display"]
+    fn second_window() {
+        let _win = Window::init(100, 100, "first").unwrap();
+        let got = Window::init(100, 100, "second");
+        assert!(got.is_err());
     }
 }
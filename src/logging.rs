@@ -0,0 +1,89 @@
+//! Route raylib's TraceLog output into the [log] crate instead of stdout.
+//! Messages are logged with the `raylib` target at the matching [log::Level].
+
+use std::{
+    ffi::{c_char, c_int, CStr},
+    sync::Once,
+};
+
+use raylib_sys::{
+    SetTraceLogCallback, SetTraceLogLevel, TraceLogCallback,
+    TraceLogLevel_LOG_ALL, TraceLogLevel_LOG_DEBUG, TraceLogLevel_LOG_ERROR,
+    TraceLogLevel_LOG_FATAL, TraceLogLevel_LOG_INFO, TraceLogLevel_LOG_NONE,
+    TraceLogLevel_LOG_TRACE, TraceLogLevel_LOG_WARNING,
+};
+
+/// The minimum level of message raylib will emit
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(i32)]
+pub enum LogLevel {
+    All = TraceLogLevel_LOG_ALL as i32,
+    Trace = TraceLogLevel_LOG_TRACE as i32,
+    Debug = TraceLogLevel_LOG_DEBUG as i32,
+    Info = TraceLogLevel_LOG_INFO as i32,
+    Warning = TraceLogLevel_LOG_WARNING as i32,
+    Error = TraceLogLevel_LOG_ERROR as i32,
+    Fatal = TraceLogLevel_LOG_FATAL as i32,
+    /// Disable raylib logging entirely
+    None = TraceLogLevel_LOG_NONE as i32,
+}
+
+/// bindgen picks a platform-specific type for the `va_list` argument of
+/// [TraceLogCallback], so pull it back out of the callback type instead of
+/// naming it directly
+trait CallbackArgs {
+    type VaList;
+}
+
+impl<T> CallbackArgs for Option<unsafe extern "C" fn(c_int, *const c_char, T)> {
+    type VaList = T;
+}
+
+type VaList = <TraceLogCallback as CallbackArgs>::VaList;
+
+extern "C" {
+    fn vsnprintf(
+        s: *mut c_char,
+        n: usize,
+        format: *const c_char,
+        args: VaList,
+    ) -> c_int;
+}
+
+/// Large enough for any message raylib itself produces
+const MAX_MESSAGE_LEN: usize = 1024;
+
+#[allow(non_upper_case_globals)]
+unsafe extern "C" fn trace_log(
+    level: c_int,
+    text: *const c_char,
+    args: VaList,
+) {
+    let level = match level as u32 {
+        TraceLogLevel_LOG_TRACE => log::Level::Trace,
+        TraceLogLevel_LOG_DEBUG => log::Level::Debug,
+        TraceLogLevel_LOG_INFO => log::Level::Info,
+        TraceLogLevel_LOG_WARNING => log::Level::Warn,
+        TraceLogLevel_LOG_ERROR | TraceLogLevel_LOG_FATAL => log::Level::Error,
+        _ => return,
+    };
+    let mut buf = [0 as c_char; MAX_MESSAGE_LEN];
+    if vsnprintf(buf.as_mut_ptr(), buf.len(), text, args) < 0 {
+        return;
+    }
+    let msg = CStr::from_ptr(buf.as_ptr()).to_string_lossy();
+    log::log!(target: "raylib", level, "{msg}");
+}
+
+/// Install the TraceLog callback. This is called automatically before
+/// anything in donkey that makes raylib log, so it only needs to be called
+/// directly before using `raylib_sys` functions.
+pub fn init() {
+    static INIT: Once = Once::new();
+    INIT.call_once(|| unsafe { SetTraceLogCallback(Some(trace_log)) });
+}
+
+/// Set the minimum level of message raylib will emit
+pub fn set_level(level: LogLevel) {
+    unsafe { SetTraceLogLevel(level as c_int) }
+}
@@ -0,0 +1,96 @@
+/// Define a fieldless enum over raylib's integer constants, along with
+/// conversions from raylib's `i32` values and to and from variant names for
+/// use in config files
+macro_rules! raylib_enum {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            $(
+                $(#[$vmeta:meta])*
+                $variant:ident = $value:path,
+            )*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        #[repr(i32)]
+        pub enum $name {
+            $(
+                $(#[$vmeta])*
+                $variant = $value as i32,
+            )*
+        }
+
+        impl $name {
+            /// Every variant, in declaration order
+            pub const ALL: &'static [Self] = &[$(Self::$variant,)*];
+        }
+
+        impl TryFrom<i32> for $name {
+            type Error = $crate::DonkeyError;
+
+            fn try_from(value: i32) -> Result<Self, Self::Error> {
+                $(
+                    if value == $value as i32 {
+                        return Ok(Self::$variant);
+                    }
+                )*
+                Err($crate::DonkeyError::InvalidValue {
+                    kind: stringify!($name),
+                    value,
+                })
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                let name = match self {
+                    $(Self::$variant => stringify!($variant),)*
+                };
+                f.write_str(name)
+            }
+        }
+
+        /// Parse a variant name, ignoring case
+        impl std::str::FromStr for $name {
+            type Err = $crate::DonkeyError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                $(
+                    if s.eq_ignore_ascii_case(stringify!($variant)) {
+                        return Ok(Self::$variant);
+                    }
+                )*
+                Err($crate::DonkeyError::UnknownName {
+                    kind: stringify!($name),
+                    name: s.to_owned(),
+                })
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{keys::Key, DonkeyError};
+
+    #[test]
+    fn key_from_i32() {
+        assert_eq!(Key::try_from(87).unwrap(), Key::W);
+        assert_eq!(Key::try_from(340).unwrap(), Key::LShift);
+        assert!(matches!(
+            Key::try_from(0),
+            Err(DonkeyError::InvalidValue { kind: "Key", value: 0 })
+        ));
+    }
+
+    #[test]
+    fn key_names() {
+        for key in Key::ALL {
+            assert_eq!(key.to_string().parse::<Key>().unwrap(), *key);
+        }
+        assert_eq!("pageup".parse::<Key>().unwrap(), Key::PageUp);
+        assert_eq!("KP_ADD".parse::<Key>().ok(), None);
+        assert_eq!(Key::Kp0.to_string(), "Kp0");
+    }
+}
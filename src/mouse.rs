@@ -0,0 +1,143 @@
+//! Mouse input and cursor control
+
+use std::ffi::c_int;
+
+use raylib_sys::{
+    DisableCursor, EnableCursor, GetMouseDelta, GetMousePosition,
+    GetMouseWheelMove, GetMouseWheelMoveV, HideCursor, IsCursorHidden,
+    IsCursorOnScreen, IsMouseButtonDown, IsMouseButtonPressed,
+    IsMouseButtonReleased, IsMouseButtonUp, SetMouseCursor, SetMouseOffset,
+    SetMousePosition, SetMouseScale, ShowCursor,
+};
+
+use crate::{Vector2, Window};
+
+raylib_enum! {
+    pub enum MouseButton {
+        Left = raylib_sys::MouseButton_MOUSE_BUTTON_LEFT,
+        Right = raylib_sys::MouseButton_MOUSE_BUTTON_RIGHT,
+        Middle = raylib_sys::MouseButton_MOUSE_BUTTON_MIDDLE,
+        /// Side button on advanced mice
+        Side = raylib_sys::MouseButton_MOUSE_BUTTON_SIDE,
+        /// Extra button on advanced mice
+        Extra = raylib_sys::MouseButton_MOUSE_BUTTON_EXTRA,
+        /// Forward button on advanced mice
+        Forward = raylib_sys::MouseButton_MOUSE_BUTTON_FORWARD,
+        /// Back button on advanced mice
+        Back = raylib_sys::MouseButton_MOUSE_BUTTON_BACK,
+    }
+}
+
+raylib_enum! {
+    /// The shape of the mouse cursor
+    pub enum MouseCursor {
+        Default = raylib_sys::MouseCursor_MOUSE_CURSOR_DEFAULT,
+        Arrow = raylib_sys::MouseCursor_MOUSE_CURSOR_ARROW,
+        /// Text writing cursor
+        Ibeam = raylib_sys::MouseCursor_MOUSE_CURSOR_IBEAM,
+        Crosshair = raylib_sys::MouseCursor_MOUSE_CURSOR_CROSSHAIR,
+        PointingHand = raylib_sys::MouseCursor_MOUSE_CURSOR_POINTING_HAND,
+        /// Horizontal resize/move arrow
+        ResizeEw = raylib_sys::MouseCursor_MOUSE_CURSOR_RESIZE_EW,
+        /// Vertical resize/move arrow
+        ResizeNs = raylib_sys::MouseCursor_MOUSE_CURSOR_RESIZE_NS,
+        /// Top-left to bottom-right diagonal resize/move arrow
+        ResizeNwse = raylib_sys::MouseCursor_MOUSE_CURSOR_RESIZE_NWSE,
+        /// Top-right to bottom-left diagonal resize/move arrow
+        ResizeNesw = raylib_sys::MouseCursor_MOUSE_CURSOR_RESIZE_NESW,
+        /// Omnidirectional resize/move cursor
+        ResizeAll = raylib_sys::MouseCursor_MOUSE_CURSOR_RESIZE_ALL,
+        NotAllowed = raylib_sys::MouseCursor_MOUSE_CURSOR_NOT_ALLOWED,
+    }
+}
+
+impl Window {
+    /// Check if a mouse button is being pressed
+    pub fn is_mouse_button_down(&self, button: MouseButton) -> bool {
+        unsafe { IsMouseButtonDown(button as c_int) }
+    }
+
+    /// Check if a mouse button has been pressed once
+    pub fn is_mouse_button_pressed(&self, button: MouseButton) -> bool {
+        unsafe { IsMouseButtonPressed(button as c_int) }
+    }
+
+    /// Check if a mouse button has been released once
+    pub fn is_mouse_button_released(&self, button: MouseButton) -> bool {
+        unsafe { IsMouseButtonReleased(button as c_int) }
+    }
+
+    /// Check if a mouse button is not being pressed
+    pub fn is_mouse_button_up(&self, button: MouseButton) -> bool {
+        unsafe { IsMouseButtonUp(button as c_int) }
+    }
+
+    /// The mouse position in screen coordinates, after applying the offset
+    /// and scale
+    pub fn mouse_position(&self) -> Vector2 {
+        unsafe { GetMousePosition() }
+    }
+
+    /// How far the mouse moved since the last frame
+    pub fn mouse_delta(&self) -> Vector2 {
+        unsafe { GetMouseDelta() }
+    }
+
+    /// Vertical mouse wheel movement this frame, or horizontal movement if
+    /// that is larger
+    pub fn mouse_wheel_move(&self) -> f32 {
+        unsafe { GetMouseWheelMove() }
+    }
+
+    /// Mouse wheel movement this frame along both axes
+    pub fn mouse_wheel_move_v(&self) -> Vector2 {
+        unsafe { GetMouseWheelMoveV() }
+    }
+
+    pub fn set_mouse_position(&self, x: i32, y: i32) {
+        unsafe { SetMousePosition(x, y) }
+    }
+
+    /// Offset the reported mouse position, for example to account for a
+    /// render target drawn at a position other than the origin
+    pub fn set_mouse_offset(&self, x: i32, y: i32) {
+        unsafe { SetMouseOffset(x, y) }
+    }
+
+    /// Scale the reported mouse position, for example to account for a
+    /// render target drawn at a different size than the window
+    pub fn set_mouse_scale(&self, x: f32, y: f32) {
+        unsafe { SetMouseScale(x, y) }
+    }
+
+    pub fn set_mouse_cursor(&self, cursor: MouseCursor) {
+        unsafe { SetMouseCursor(cursor as c_int) }
+    }
+
+    pub fn show_cursor(&self) {
+        unsafe { ShowCursor() }
+    }
+
+    pub fn hide_cursor(&self) {
+        unsafe { HideCursor() }
+    }
+
+    pub fn is_cursor_hidden(&self) -> bool {
+        unsafe { IsCursorHidden() }
+    }
+
+    /// Unlock the cursor after [Window::disable_cursor]
+    pub fn enable_cursor(&self) {
+        unsafe { EnableCursor() }
+    }
+
+    /// Hide the cursor and lock it to the window, for mouse-look controls
+    pub fn disable_cursor(&self) {
+        unsafe { DisableCursor() }
+    }
+
+    /// Check if the cursor is within the window
+    pub fn is_cursor_on_screen(&self) -> bool {
+        unsafe { IsCursorOnScreen() }
+    }
+}
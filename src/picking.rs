@@ -0,0 +1,245 @@
+//! Find the object under the mouse. Add a [Shape] to a [Picker] for each
+//! pickable object as it is drawn, then pick with the ray from
+//! [Window::mouse_ray](crate::Window::mouse_ray):
+//!
+//! ```notrust
+//! let mut picker = Picker::new();
+//! canvas.mode3d(&camera, |c3d| {
+//!     for atom in &atoms {
+//!         c3d.draw_sphere(atom.position, atom.radius, atom.color);
+//!         picker.add_sphere(atom.position, atom.radius);
+//!     }
+//! });
+//! if win.is_mouse_button_pressed(MouseButton::Left) {
+//!     if let Some(pick) = picker.pick(win.mouse_ray(&camera)) {
+//!         selected = Some(pick.index);
+//!         arcball.recenter(&mut camera, pick.collision.point);
+//!     }
+//! }
+//! ```
+
+use raylib_sys::{Ray, RayCollision};
+
+use crate::{BoundingBox, Matrix, Vector3};
+
+/// Something that can be picked
+#[derive(Clone, Copy, Debug)]
+pub enum Shape<'a> {
+    Sphere {
+        center: Vector3,
+        radius: f32,
+    },
+    Box(BoundingBox),
+    Triangle([Vector3; 3]),
+    /// corners in order around the edge
+    Quad([Vector3; 4]),
+    /// a mesh placed by `transform`, see [Ray::collide_triangles]
+    Triangles {
+        vertices: &'a [Vector3],
+        indices: Option<&'a [u16]>,
+        transform: Matrix,
+    },
+}
+
+impl Shape<'_> {
+    pub fn collide(&self, ray: &Ray) -> RayCollision {
+        match *self {
+            Shape::Sphere { center, radius } => {
+                ray.collide_sphere(center, radius)
+            }
+            Shape::Box(bounds) => ray.collide_box(bounds),
+            Shape::Triangle([a, b, c]) => ray.collide_triangle(a, b, c),
+            Shape::Quad([a, b, c, d]) => ray.collide_quad(a, b, c, d),
+            Shape::Triangles { vertices, indices, transform } => {
+                ray.collide_triangles(vertices, indices, transform)
+            }
+        }
+    }
+}
+
+/// The nearest [Shape] hit by a ray
+#[derive(Clone, Copy, Debug)]
+pub struct Pick {
+    /// the position of the shape in the [Picker]
+    pub index: usize,
+    pub collision: RayCollision,
+}
+
+/// The shapes that can be picked this frame, numbered in the order they were
+/// added. Meshes are borrowed for as long as the picker holds them
+#[derive(Clone, Debug, Default)]
+pub struct Picker<'a> {
+    shapes: Vec<Shape<'a>>,
+}
+
+impl<'a> Picker<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add `shape` and return its index
+    pub fn push(&mut self, shape: Shape<'a>) -> usize {
+        self.shapes.push(shape);
+        self.shapes.len() - 1
+    }
+
+    pub fn add_sphere(&mut self, center: Vector3, radius: f32) -> usize {
+        self.push(Shape::Sphere { center, radius })
+    }
+
+    pub fn add_box(&mut self, bounds: BoundingBox) -> usize {
+        self.push(Shape::Box(bounds))
+    }
+
+    pub fn add_triangle(
+        &mut self,
+        a: Vector3,
+        b: Vector3,
+        c: Vector3,
+    ) -> usize {
+        self.push(Shape::Triangle([a, b, c]))
+    }
+
+    pub fn add_quad(
+        &mut self,
+        a: Vector3,
+        b: Vector3,
+        c: Vector3,
+        d: Vector3,
+    ) -> usize {
+        self.push(Shape::Quad([a, b, c, d]))
+    }
+
+    /// Add a mesh whose triangles are every three `indices`, or every three
+    /// `vertices` if there are no indices, placed by `transform`
+    pub fn add_triangles(
+        &mut self,
+        vertices: &'a [Vector3],
+        indices: Option<&'a [u16]>,
+        transform: Matrix,
+    ) -> usize {
+        self.push(Shape::Triangles { vertices, indices, transform })
+    }
+
+    /// Remove all of the shapes, to start a new frame
+    pub fn clear(&mut self) {
+        self.shapes.clear();
+    }
+
+    pub fn len(&self) -> usize {
+        self.shapes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.shapes.is_empty()
+    }
+
+    /// The nearest shape in front of `ray`, if any
+    pub fn pick(&self, ray: Ray) -> Option<Pick> {
+        self.shapes
+            .iter()
+            .enumerate()
+            .map(|(index, shape)| Pick {
+                index,
+                collision: shape.collide(&ray),
+            })
+            .filter(|pick| pick.collision.hit && pick.collision.distance >= 0.0)
+            .min_by(|a, b| {
+                a.collision.distance.total_cmp(&b.collision.distance)
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn v(x: f32, y: f32, z: f32) -> Vector3 {
+        Vector3::new(x, y, z)
+    }
+
+    #[test]
+    fn nearest() {
+        let mut picker = Picker::new();
+        picker.add_sphere(v(0.0, 0.0, -5.0), 1.0);
+        picker.add_sphere(v(0.0, 0.0, 5.0), 1.0);
+        picker.add_box(BoundingBox {
+            min: v(-1.0, -1.0, 1.0),
+            max: v(1.0, 1.0, 2.0),
+        });
+        picker.add_sphere(v(5.0, 0.0, 3.0), 1.0);
+        assert_eq!(picker.len(), 4);
+
+        let ray = Ray::new(v(0.0, 0.0, 10.0), v(0.0, 0.0, -1.0));
+        let pick = picker.pick(ray).unwrap();
+        assert_eq!(pick.index, 1);
+        assert!((pick.collision.distance - 4.0).abs() < 1e-5);
+
+        let ray = Ray::new(v(0.0, 0.0, 3.0), v(0.0, 0.0, -1.0));
+        assert_eq!(picker.pick(ray).unwrap().index, 2);
+
+        // from inside the box, where the ray leaves it
+        let ray = Ray::new(v(0.0, 0.0, 1.5), v(0.0, 0.0, -1.0));
+        let pick = picker.pick(ray).unwrap();
+        assert_eq!(pick.index, 2);
+        assert!((pick.collision.distance - 0.5).abs() < 1e-5);
+
+        let ray = Ray::new(v(3.0, 0.0, 10.0), v(0.0, 0.0, -1.0));
+        assert!(picker.pick(ray).is_none());
+
+        picker.clear();
+        assert!(picker.is_empty());
+    }
+
+    #[test]
+    fn triangles_and_quads() {
+        let mut picker = Picker::new();
+        let (a, b, c, d) = (
+            v(0.0, 0.0, 0.0),
+            v(1.0, 0.0, 0.0),
+            v(1.0, 1.0, 0.0),
+            v(0.0, 1.0, 0.0),
+        );
+        picker.add_triangle(a, b, c);
+        picker.add_quad(a, b, c, d);
+        let ray = Ray::new(v(0.2, 0.8, 1.0), v(0.0, 0.0, -1.0));
+        assert_eq!(picker.pick(ray).unwrap().index, 1);
+    }
+
+    #[test]
+    fn meshes() {
+        // a unit square in the xy plane, as two indexed triangles
+        let vertices = [
+            v(0.0, 0.0, 0.0),
+            v(1.0, 0.0, 0.0),
+            v(1.0, 1.0, 0.0),
+            v(0.0, 1.0, 0.0),
+        ];
+        let indices = [0, 1, 2, 0, 2, 3];
+        let mut picker = Picker::new();
+        picker.add_sphere(v(0.5, 0.5, -3.0), 1.0);
+        picker.add_triangles(&vertices, Some(&indices), Matrix::identity());
+        picker.add_triangles(
+            &vertices,
+            Some(&indices),
+            Matrix::translate(0.0, 0.0, 2.0),
+        );
+
+        let ray = Ray::new(v(0.2, 0.8, 5.0), v(0.0, 0.0, -1.0));
+        let pick = picker.pick(ray).unwrap();
+        assert_eq!(pick.index, 2);
+        assert!((pick.collision.distance - 3.0).abs() < 1e-5);
+
+        // past the edge of the squares
+        let ray = Ray::new(v(0.5, 1.2, 5.0), v(0.0, 0.0, -1.0));
+        assert_eq!(picker.pick(ray).unwrap().index, 0);
+
+        // without indices the vertices are the triangles
+        let mut picker = Picker::new();
+        picker.add_triangles(&vertices[..3], None, Matrix::identity());
+        let ray = Ray::new(v(0.8, 0.2, 1.0), v(0.0, 0.0, -1.0));
+        assert_eq!(picker.pick(ray).unwrap().index, 0);
+        let ray = Ray::new(v(0.2, 0.8, 1.0), v(0.0, 0.0, -1.0));
+        assert!(picker.pick(ray).is_none());
+    }
+}
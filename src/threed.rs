@@ -0,0 +1,80 @@
+//! 3D drawing primitives
+
+use raylib_sys::{
+    DrawBoundingBox, DrawCube, DrawCylinderEx, DrawSphere, GetMousePosition,
+    GetScreenToWorldRay, GetWorldToScreen, Ray,
+};
+
+use crate::{
+    colors::IntoColor, draw::Mode3D, BoundingBox, Camera3D, Vector2, Vector3,
+    Window,
+};
+
+impl Mode3D<'_> {
+    pub fn draw_cube(
+        &self,
+        center: impl Into<Vector3>,
+        width: f32,
+        height: f32,
+        length: f32,
+        color: impl IntoColor,
+    ) {
+        unsafe { DrawCube(center.into(), width, height, length, color.into()) }
+    }
+
+    pub fn draw_sphere(
+        &self,
+        center: impl Into<Vector3>,
+        radius: f32,
+        color: impl IntoColor,
+    ) {
+        unsafe { DrawSphere(center.into(), radius, color.into()) }
+    }
+
+    /// draw a cylinder from `start` to `end` with constant `radius`
+    pub fn draw_cylinder(
+        &self,
+        start: impl Into<Vector3>,
+        end: impl Into<Vector3>,
+        radius: f32,
+        color: impl IntoColor,
+    ) {
+        let (start, end) = (start.into(), end.into());
+        unsafe { DrawCylinderEx(start, end, radius, radius, 8, color.into()) }
+    }
+
+    /// draw the edges of `bounds`
+    pub fn draw_bounding_box(
+        &self,
+        bounds: BoundingBox,
+        color: impl IntoColor,
+    ) {
+        unsafe { DrawBoundingBox(bounds, color.into()) }
+    }
+}
+
+impl Window {
+    /// The ray from `camera` through the mouse cursor, for picking objects
+    /// with [Picker](crate::picking::Picker)
+    pub fn mouse_ray(&self, camera: &Camera3D) -> Ray {
+        unsafe { GetScreenToWorldRay(GetMousePosition(), *camera) }
+    }
+
+    /// The ray from `camera` through screen position `position`
+    pub fn screen_to_world_ray(
+        &self,
+        position: impl Into<Vector2>,
+        camera: &Camera3D,
+    ) -> Ray {
+        unsafe { GetScreenToWorldRay(position.into(), *camera) }
+    }
+
+    /// The screen position where `camera` draws world position `position`
+    pub fn world_to_screen(
+        &self,
+        position: impl Into<Vector3>,
+        camera: &Camera3D,
+    ) -> Vector2 {
+        unsafe { GetWorldToScreen(position.into(), *camera) }
+    }
+}
@@ -1,10 +1,25 @@
 //! 2D drawing primitives
+//!
+//! Coordinates are signed so shapes can hang off the edges of the screen.
+//! Angles are in degrees, clockwise from the positive x axis, and a
+//! `segments` count below the minimum for a smooth curve is raised to it
 
 use std::ffi::c_int;
 
-use raylib_sys::{DrawRectangle, DrawRectangleRec, DrawRectangleV, Rectangle};
+use raylib_sys::{
+    DrawCircle, DrawCircleGradient, DrawCircleLines, DrawCircleLinesV,
+    DrawCircleSector, DrawCircleSectorLines, DrawCircleV, DrawEllipse,
+    DrawEllipseLines, DrawLine, DrawLineBezier, DrawLineEx, DrawLineStrip,
+    DrawLineV, DrawPixel, DrawPixelV, DrawPoly, DrawPolyLines, DrawPolyLinesEx,
+    DrawRectangle, DrawRectangleGradientEx, DrawRectangleGradientH,
+    DrawRectangleGradientV, DrawRectangleLines, DrawRectangleLinesEx,
+    DrawRectanglePro, DrawRectangleRec, DrawRectangleRounded,
+    DrawRectangleRoundedLines, DrawRectangleRoundedLinesEx, DrawRectangleV,
+    DrawRing, DrawRingLines, DrawTriangle, DrawTriangleFan, DrawTriangleLines,
+    DrawTriangleStrip, GetScreenToWorld2D, GetWorldToScreen2D, Rectangle,
+};
 
-use crate::{colors::IntoColor, Window};
+use crate::{colors::IntoColor, draw::DrawHandle, Camera2D, Window};
 
 pub use raylib_sys::Vector2;
 
@@ -15,9 +30,25 @@ macro_rules! vector2 {
     };
 }
 
-impl Window {
+impl DrawHandle<'_> {
     /// draw a color-filled rectangle
     pub fn draw_rectangle(
+        &self,
+        x: i32,
+        y: i32,
+        w: i32,
+        h: i32,
+        color: impl IntoColor,
+    ) {
+        unsafe {
+            DrawRectangle(x, y, w, h, color.into());
+        }
+    }
+
+    /// draw a color-filled rectangle with `usize` coordinates. See
+    /// [DrawHandle::draw_rectangle]
+    #[deprecated(note = "use `draw_rectangle`, which takes `i32` coordinates")]
+    pub fn draw_rectangle_usize(
         &self,
         x: usize,
         y: usize,
@@ -25,26 +56,20 @@ impl Window {
         h: usize,
         color: impl IntoColor,
     ) {
-        unsafe {
-            DrawRectangle(
-                x as c_int,
-                y as c_int,
-                w as c_int,
-                h as c_int,
-                color.into(),
-            );
-        }
+        self.draw_rectangle(
+            x as c_int, y as c_int, w as c_int, h as c_int, color,
+        );
     }
 
     /// draw a color-filled rectangle with vector `position` and `size`
     pub fn draw_rectangle_v(
         &self,
-        position: Vector2,
-        size: Vector2,
+        position: impl Into<Vector2>,
+        size: impl Into<Vector2>,
         color: impl IntoColor,
     ) {
         unsafe {
-            DrawRectangleV(position, size, color.into());
+            DrawRectangleV(position.into(), size.into(), color.into());
         }
     }
 
@@ -54,6 +79,515 @@ impl Window {
             DrawRectangleRec(rec, color.into());
         }
     }
+
+    /// draw a color-filled rectangle rotated by `rotation` degrees around
+    /// `origin`, relative to the top left corner of `rec`
+    pub fn draw_rectangle_pro(
+        &self,
+        rec: Rectangle,
+        origin: impl Into<Vector2>,
+        rotation: f32,
+        color: impl IntoColor,
+    ) {
+        unsafe { DrawRectanglePro(rec, origin.into(), rotation, color.into()) }
+    }
+
+    /// draw a rectangle outline
+    pub fn draw_rectangle_lines(
+        &self,
+        x: i32,
+        y: i32,
+        w: i32,
+        h: i32,
+        color: impl IntoColor,
+    ) {
+        unsafe { DrawRectangleLines(x, y, w, h, color.into()) }
+    }
+
+    /// draw a rectangle outline `thick` pixels wide, inside `rec`
+    pub fn draw_rectangle_lines_ex(
+        &self,
+        rec: Rectangle,
+        thick: f32,
+        color: impl IntoColor,
+    ) {
+        unsafe { DrawRectangleLinesEx(rec, thick, color.into()) }
+    }
+
+    /// draw a rectangle with rounded corners. `roundness` goes from 0 for
+    /// square corners to 1 for fully round ends
+    pub fn draw_rectangle_rounded(
+        &self,
+        rec: Rectangle,
+        roundness: f32,
+        segments: i32,
+        color: impl IntoColor,
+    ) {
+        unsafe { DrawRectangleRounded(rec, roundness, segments, color.into()) }
+    }
+
+    /// draw the outline of [DrawHandle::draw_rectangle_rounded]
+    pub fn draw_rectangle_rounded_lines(
+        &self,
+        rec: Rectangle,
+        roundness: f32,
+        segments: i32,
+        color: impl IntoColor,
+    ) {
+        unsafe {
+            DrawRectangleRoundedLines(rec, roundness, segments, color.into())
+        }
+    }
+
+    /// draw the outline of [DrawHandle::draw_rectangle_rounded], `thick`
+    /// pixels wide
+    pub fn draw_rectangle_rounded_lines_ex(
+        &self,
+        rec: Rectangle,
+        roundness: f32,
+        segments: i32,
+        thick: f32,
+        color: impl IntoColor,
+    ) {
+        unsafe {
+            DrawRectangleRoundedLinesEx(
+                rec,
+                roundness,
+                segments,
+                thick,
+                color.into(),
+            )
+        }
+    }
+
+    /// draw a rectangle fading from `top` to `bottom`
+    pub fn draw_rectangle_gradient_v(
+        &self,
+        x: i32,
+        y: i32,
+        w: i32,
+        h: i32,
+        top: impl IntoColor,
+        bottom: impl IntoColor,
+    ) {
+        unsafe { DrawRectangleGradientV(x, y, w, h, top.into(), bottom.into()) }
+    }
+
+    /// draw a rectangle fading from `left` to `right`
+    pub fn draw_rectangle_gradient_h(
+        &self,
+        x: i32,
+        y: i32,
+        w: i32,
+        h: i32,
+        left: impl IntoColor,
+        right: impl IntoColor,
+    ) {
+        unsafe { DrawRectangleGradientH(x, y, w, h, left.into(), right.into()) }
+    }
+
+    /// draw a rectangle blending between a color at each corner
+    pub fn draw_rectangle_gradient_ex(
+        &self,
+        rec: Rectangle,
+        top_left: impl IntoColor,
+        bottom_left: impl IntoColor,
+        top_right: impl IntoColor,
+        bottom_right: impl IntoColor,
+    ) {
+        unsafe {
+            DrawRectangleGradientEx(
+                rec,
+                top_left.into(),
+                bottom_left.into(),
+                top_right.into(),
+                bottom_right.into(),
+            )
+        }
+    }
+
+    pub fn draw_pixel(&self, x: i32, y: i32, color: impl IntoColor) {
+        unsafe { DrawPixel(x, y, color.into()) }
+    }
+
+    pub fn draw_pixel_v(
+        &self,
+        position: impl Into<Vector2>,
+        color: impl IntoColor,
+    ) {
+        unsafe { DrawPixelV(position.into(), color.into()) }
+    }
+
+    /// draw a one pixel wide line
+    pub fn draw_line(
+        &self,
+        start_x: i32,
+        start_y: i32,
+        end_x: i32,
+        end_y: i32,
+        color: impl IntoColor,
+    ) {
+        unsafe { DrawLine(start_x, start_y, end_x, end_y, color.into()) }
+    }
+
+    /// draw a one pixel wide line
+    pub fn draw_line_v(
+        &self,
+        start: impl Into<Vector2>,
+        end: impl Into<Vector2>,
+        color: impl IntoColor,
+    ) {
+        unsafe { DrawLineV(start.into(), end.into(), color.into()) }
+    }
+
+    /// draw a line `thick` pixels wide
+    pub fn draw_line_ex(
+        &self,
+        start: impl Into<Vector2>,
+        end: impl Into<Vector2>,
+        thick: f32,
+        color: impl IntoColor,
+    ) {
+        unsafe { DrawLineEx(start.into(), end.into(), thick, color.into()) }
+    }
+
+    /// draw an S-shaped cubic bezier curve `thick` pixels wide, leaving `start`
+    /// and arriving at `end` horizontally
+    pub fn draw_line_bezier(
+        &self,
+        start: impl Into<Vector2>,
+        end: impl Into<Vector2>,
+        thick: f32,
+        color: impl IntoColor,
+    ) {
+        unsafe { DrawLineBezier(start.into(), end.into(), thick, color.into()) }
+    }
+
+    /// draw one pixel wide lines joining `points` in order
+    pub fn draw_line_strip(&self, points: &[Vector2], color: impl IntoColor) {
+        unsafe {
+            DrawLineStrip(points.as_ptr(), points.len() as c_int, color.into())
+        }
+    }
+
+    pub fn draw_circle(
+        &self,
+        center_x: i32,
+        center_y: i32,
+        radius: f32,
+        color: impl IntoColor,
+    ) {
+        unsafe { DrawCircle(center_x, center_y, radius, color.into()) }
+    }
+
+    pub fn draw_circle_v(
+        &self,
+        center: impl Into<Vector2>,
+        radius: f32,
+        color: impl IntoColor,
+    ) {
+        unsafe { DrawCircleV(center.into(), radius, color.into()) }
+    }
+
+    /// draw a circle fading from `inner` at the center to `outer` at the edge
+    pub fn draw_circle_gradient(
+        &self,
+        center_x: i32,
+        center_y: i32,
+        radius: f32,
+        inner: impl IntoColor,
+        outer: impl IntoColor,
+    ) {
+        unsafe {
+            DrawCircleGradient(
+                center_x,
+                center_y,
+                radius,
+                inner.into(),
+                outer.into(),
+            )
+        }
+    }
+
+    /// draw a circle outline
+    pub fn draw_circle_lines(
+        &self,
+        center_x: i32,
+        center_y: i32,
+        radius: f32,
+        color: impl IntoColor,
+    ) {
+        unsafe { DrawCircleLines(center_x, center_y, radius, color.into()) }
+    }
+
+    /// draw a circle outline
+    pub fn draw_circle_lines_v(
+        &self,
+        center: impl Into<Vector2>,
+        radius: f32,
+        color: impl IntoColor,
+    ) {
+        unsafe { DrawCircleLinesV(center.into(), radius, color.into()) }
+    }
+
+    /// draw a pie slice from `start_angle` to `end_angle`
+    pub fn draw_circle_sector(
+        &self,
+        center: impl Into<Vector2>,
+        radius: f32,
+        start_angle: f32,
+        end_angle: f32,
+        segments: i32,
+        color: impl IntoColor,
+    ) {
+        unsafe {
+            DrawCircleSector(
+                center.into(),
+                radius,
+                start_angle,
+                end_angle,
+                segments,
+                color.into(),
+            )
+        }
+    }
+
+    /// draw the outline of [DrawHandle::draw_circle_sector]
+    pub fn draw_circle_sector_lines(
+        &self,
+        center: impl Into<Vector2>,
+        radius: f32,
+        start_angle: f32,
+        end_angle: f32,
+        segments: i32,
+        color: impl IntoColor,
+    ) {
+        unsafe {
+            DrawCircleSectorLines(
+                center.into(),
+                radius,
+                start_angle,
+                end_angle,
+                segments,
+                color.into(),
+            )
+        }
+    }
+
+    /// draw the part of a ring between `inner_radius` and `outer_radius`
+    /// from `start_angle` to `end_angle`
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw_ring(
+        &self,
+        center: impl Into<Vector2>,
+        inner_radius: f32,
+        outer_radius: f32,
+        start_angle: f32,
+        end_angle: f32,
+        segments: i32,
+        color: impl IntoColor,
+    ) {
+        unsafe {
+            DrawRing(
+                center.into(),
+                inner_radius,
+                outer_radius,
+                start_angle,
+                end_angle,
+                segments,
+                color.into(),
+            )
+        }
+    }
+
+    /// draw the outline of [DrawHandle::draw_ring]
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw_ring_lines(
+        &self,
+        center: impl Into<Vector2>,
+        inner_radius: f32,
+        outer_radius: f32,
+        start_angle: f32,
+        end_angle: f32,
+        segments: i32,
+        color: impl IntoColor,
+    ) {
+        unsafe {
+            DrawRingLines(
+                center.into(),
+                inner_radius,
+                outer_radius,
+                start_angle,
+                end_angle,
+                segments,
+                color.into(),
+            )
+        }
+    }
+
+    /// draw an ellipse with horizontal radius `radius_h` and vertical radius
+    /// `radius_v`
+    pub fn draw_ellipse(
+        &self,
+        center_x: i32,
+        center_y: i32,
+        radius_h: f32,
+        radius_v: f32,
+        color: impl IntoColor,
+    ) {
+        unsafe {
+            DrawEllipse(center_x, center_y, radius_h, radius_v, color.into())
+        }
+    }
+
+    /// draw the outline of [DrawHandle::draw_ellipse]
+    pub fn draw_ellipse_lines(
+        &self,
+        center_x: i32,
+        center_y: i32,
+        radius_h: f32,
+        radius_v: f32,
+        color: impl IntoColor,
+    ) {
+        unsafe {
+            DrawEllipseLines(
+                center_x,
+                center_y,
+                radius_h,
+                radius_v,
+                color.into(),
+            )
+        }
+    }
+
+    /// draw a color-filled triangle. The corners must be counter-clockwise on
+    /// screen, or nothing is drawn
+    pub fn draw_triangle(
+        &self,
+        v1: impl Into<Vector2>,
+        v2: impl Into<Vector2>,
+        v3: impl Into<Vector2>,
+        color: impl IntoColor,
+    ) {
+        unsafe { DrawTriangle(v1.into(), v2.into(), v3.into(), color.into()) }
+    }
+
+    /// draw a triangle outline
+    pub fn draw_triangle_lines(
+        &self,
+        v1: impl Into<Vector2>,
+        v2: impl Into<Vector2>,
+        v3: impl Into<Vector2>,
+        color: impl IntoColor,
+    ) {
+        unsafe {
+            DrawTriangleLines(v1.into(), v2.into(), v3.into(), color.into())
+        }
+    }
+
+    /// draw triangles sharing the first of `points`, each joining it to the
+    /// next two. Counter-clockwise on screen like [DrawHandle::draw_triangle]
+    pub fn draw_triangle_fan(&self, points: &[Vector2], color: impl IntoColor) {
+        unsafe {
+            DrawTriangleFan(
+                points.as_ptr(),
+                points.len() as c_int,
+                color.into(),
+            )
+        }
+    }
+
+    /// draw a triangle for every three consecutive `points`
+    pub fn draw_triangle_strip(
+        &self,
+        points: &[Vector2],
+        color: impl IntoColor,
+    ) {
+        unsafe {
+            DrawTriangleStrip(
+                points.as_ptr(),
+                points.len() as c_int,
+                color.into(),
+            )
+        }
+    }
+
+    /// draw a convex polygon with corners `points` in counter-clockwise order
+    /// on screen
+    pub fn draw_convex_poly(&self, points: &[Vector2], color: impl IntoColor) {
+        self.draw_triangle_fan(points, color);
+    }
+
+    /// draw a regular polygon with `sides` corners `radius` from the center,
+    /// the first of them `rotation` degrees around
+    pub fn draw_poly(
+        &self,
+        center: impl Into<Vector2>,
+        sides: i32,
+        radius: f32,
+        rotation: f32,
+        color: impl IntoColor,
+    ) {
+        unsafe {
+            DrawPoly(center.into(), sides, radius, rotation, color.into())
+        }
+    }
+
+    /// draw the outline of [DrawHandle::draw_poly]
+    pub fn draw_poly_lines(
+        &self,
+        center: impl Into<Vector2>,
+        sides: i32,
+        radius: f32,
+        rotation: f32,
+        color: impl IntoColor,
+    ) {
+        unsafe {
+            DrawPolyLines(center.into(), sides, radius, rotation, color.into())
+        }
+    }
+
+    /// draw the outline of [DrawHandle::draw_poly], `thick` pixels wide
+    pub fn draw_poly_lines_ex(
+        &self,
+        center: impl Into<Vector2>,
+        sides: i32,
+        radius: f32,
+        rotation: f32,
+        thick: f32,
+        color: impl IntoColor,
+    ) {
+        unsafe {
+            DrawPolyLinesEx(
+                center.into(),
+                sides,
+                radius,
+                rotation,
+                thick,
+                color.into(),
+            )
+        }
+    }
+}
+
+impl Window {
+    /// The world position drawn at screen position `position` by `camera`
+    pub fn screen_to_world_2d(
+        &self,
+        position: Vector2,
+        camera: &Camera2D,
+    ) -> Vector2 {
+        unsafe { GetScreenToWorld2D(position, *camera) }
+    }
+
+    /// The screen position where `camera` draws world position `position`
+    pub fn world_to_screen_2d(
+        &self,
+        position: Vector2,
+        camera: &Camera2D,
+    ) -> Vector2 {
+        unsafe { GetWorldToScreen2D(position, *camera) }
+    }
 }
 
 #[cfg(test)]